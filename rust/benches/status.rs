@@ -0,0 +1,63 @@
+//! ステータス計算のベンチマーク。メモ化などの最適化前後の比較用。
+//! `cargo bench --bench status`
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ff11sim::prelude::*;
+use ff11sim::status::calc_status;
+
+fn war99() -> Chara {
+    Chara::builder()
+        .race(Race::Hum)
+        .main_job(Job::War, 99)
+        .support_job(Job::Drg, 59)
+        .master_lv(50)
+        .build()
+        .unwrap()
+}
+
+fn bench_calc_status(c: &mut Criterion) {
+    c.bench_function("calc_status/all_kinds_lv99", |b| {
+        b.iter(|| {
+            StatusKind::VARIANTS
+                .iter()
+                .map(|&kind| calc_status(kind, black_box(Grade::B), black_box(99)))
+                .sum::<f32>()
+        })
+    });
+}
+
+fn bench_chara_status(c: &mut Criterion) {
+    let chara = war99();
+    c.bench_function("chara/war99_to_status", |b| {
+        b.iter(|| black_box(&chara).to_status())
+    });
+
+    // War99 ML50 で全ジョブをサポートにした場合 (サポートなし + 21 ジョブ)
+    c.bench_function("chara/war99_all_supports", |b| {
+        b.iter(|| {
+            std::iter::once(None)
+                .chain(
+                    Job::VARIANTS
+                        .iter()
+                        .filter(|&&job| job != Job::War)
+                        .map(Some),
+                )
+                .map(|support| {
+                    let mut builder = Chara::builder()
+                        .race(Race::Hum)
+                        .main_job(Job::War, 99)
+                        .master_lv(50);
+                    if let Some(&job) = support {
+                        builder = builder.support_job_auto(job, 99);
+                    }
+                    builder.build().unwrap().to_status()
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_calc_status, bench_chara_status);
+criterion_main!(benches);
@@ -0,0 +1,141 @@
+//! 全種族 × 全ジョブ × Lv1-99 の「種族 + メインジョブ」ステータスを事前計算し、
+//! `$OUT_DIR/precomputed_status.bin` に書き出す (`src/precomputed.rs` が `include_bytes!` で埋め込む)。
+//!
+//! 計算式は `src/formula.rs` をそのまま取り込んで共有し、種族・ジョブ・ステータスの並びは
+//! 各 enum の定義から読み取る。結果が一致することは `precomputed` のテストで全組み合わせを検証する。
+//!
+//! あわせて、グレード表の出典ファイル一覧と最終更新日を `status::DATA_SOURCE` / `DATA_UPDATED` 用の
+//! 環境変数として渡す。
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use serde_json::Value;
+
+// build script からは一部の項目しか使わない
+#[allow(dead_code)]
+#[path = "src/formula.rs"]
+mod formula;
+
+use formula::{status_from_row, RoundingStrategy, MAX_GROWTH_LV};
+
+/// ステータス計算に使うグレード表 (`data/` 以下)
+const GRADE_DATA_FILES: [&str; 3] = [
+    "grade_coefficients.json",
+    "race_status_grades.json",
+    "job_status_grades.json",
+];
+
+fn read_data(name: &str) -> Value {
+    let path = format!("../data/{}", name);
+    println!("cargo:rerun-if-changed={}", path);
+    let json = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let file: Value = serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    file["data"].clone()
+}
+
+/// グレード表のいずれかを最後に変更したコミットの日付 (YYYY-MM-DD)。git 履歴が無ければ "unknown"
+fn grade_data_updated() -> String {
+    let paths = GRADE_DATA_FILES.map(|name| format!("../data/{}", name));
+    Command::new("git")
+        .args(["log", "-1", "--format=%cd", "--date=short", "--"])
+        .args(&paths)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|date| date.trim().to_string())
+        .filter(|date| !date.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// `src/{file}` の `pub enum {name} { ... }` からバリアント名を定義順に取り出す
+/// (EnumMap の並びと一致させるため、手書きの一覧ではなく enum 定義そのものを参照する)
+fn enum_variants(file: &str, name: &str) -> Vec<String> {
+    let path = format!("src/{}", file);
+    println!("cargo:rerun-if-changed={}", path);
+    let source = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let start = source
+        .find(&format!("pub enum {} {{", name))
+        .unwrap_or_else(|| panic!("{}: enum {} not found", path, name));
+    let body = &source[start..];
+    let body = &body[body.find('{').unwrap() + 1..body.find('}').unwrap()];
+    body.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//") && !line.starts_with('#'))
+        .map(|line| line.trim_end_matches(',').to_string())
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/formula.rs");
+    let races = enum_variants("race.rs", "Race");
+    let jobs = enum_variants("job.rs", "Job");
+    let status_kinds = enum_variants("status.rs", "StatusKind");
+    let [coefficients, race_grades, job_grades] = GRADE_DATA_FILES.map(read_data);
+    println!(
+        "cargo:rustc-env=FF11SIM_GRADE_DATA_FILES={}",
+        GRADE_DATA_FILES
+            .map(|name| format!("data/{}", name))
+            .join(", ")
+    );
+    println!(
+        "cargo:rustc-env=FF11SIM_GRADE_DATA_UPDATED={}",
+        grade_data_updated()
+    );
+
+    let coef_table = |kind: &str| -> BTreeMap<String, Vec<f32>> {
+        let column = if kind == "Hp" || kind == "Mp" {
+            "hpmp"
+        } else {
+            "bp"
+        };
+        serde_json::from_value(coefficients[column].clone()).expect("invalid grade coefficients")
+    };
+    let status = |grade: &Value, kind: &str, lv: i32| -> f32 {
+        match grade.as_str() {
+            Some(grade) => {
+                let row = &coef_table(kind)[grade];
+                let bands: [f32; 4] = row[..4]
+                    .try_into()
+                    .expect("grade coefficients need 4 columns");
+                let coef_30plus = (kind == "Hp" || kind == "Mp").then(|| row[4]);
+                status_from_row(&bands, coef_30plus, lv, RoundingStrategy::PerBand)
+            }
+            None => 0.0,
+        }
+    };
+
+    // レコード: (種族, ジョブ, Lv, ステータス) の順に、値を 0.5 単位の u16 (little endian) で格納
+    let mut bytes = Vec::with_capacity(
+        races.len() * jobs.len() * MAX_GROWTH_LV as usize * status_kinds.len() * 2,
+    );
+    for race in &races {
+        for job in &jobs {
+            for lv in 1..=MAX_GROWTH_LV {
+                for kind in &status_kinds {
+                    let value = status(&race_grades[race][kind], kind, lv)
+                        + status(&job_grades[job][kind], kind, lv);
+                    let halves = value * 2.0;
+                    assert!(
+                        halves.fract() == 0.0 && (0.0..=u16::MAX as f32).contains(&halves),
+                        "{} {} Lv{} {}: {} is not representable",
+                        race,
+                        job,
+                        lv,
+                        kind,
+                        value
+                    );
+                    bytes.extend((halves as u16).to_le_bytes());
+                }
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("precomputed_status.bin");
+    fs::write(out, bytes).expect("failed to write precomputed_status.bin");
+}
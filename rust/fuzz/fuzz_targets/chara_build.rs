@@ -0,0 +1,58 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use ff11sim::chara::Chara;
+use ff11sim::job::Job;
+use ff11sim::race::Race;
+use ff11sim::status::{MeritPoints, VariantArray};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    race: u8,
+    job: u8,
+    lv: i32,
+    support: Option<(u8, i32)>,
+    support_auto: bool,
+    master_lv: i32,
+    merits: [i32; 9],
+}
+
+// 任意の入力で build() / status 計算が panic しないこと (範囲外は Err になる)
+fuzz_target!(|input: Input| {
+    let race = Race::VARIANTS[input.race as usize % Race::VARIANTS.len()];
+    let job = Job::VARIANTS[input.job as usize % Job::VARIANTS.len()];
+    let [hp, mp, str_, dex, vit, agi, int, mnd, chr] = input.merits;
+    let merit_points = MeritPoints {
+        hp,
+        mp,
+        str_,
+        dex,
+        vit,
+        agi,
+        int,
+        mnd,
+        chr,
+        ..Default::default()
+    };
+
+    let mut builder = Chara::builder()
+        .race(race)
+        .main_job(job, input.lv)
+        .master_lv(input.master_lv)
+        .merit_points(merit_points);
+    if let Some((sub, sub_lv)) = input.support {
+        let sub = Job::VARIANTS[sub as usize % Job::VARIANTS.len()];
+        builder = if input.support_auto {
+            builder.support_job_auto(sub, sub_lv)
+        } else {
+            builder.support_job(sub, sub_lv)
+        };
+    }
+
+    if let Ok(chara) = builder.build() {
+        let _ = chara.status_map();
+        let _ = chara.total_bp();
+        let _ = chara.total_hpmp();
+    }
+});
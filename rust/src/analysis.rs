@@ -0,0 +1,388 @@
+use crate::chara::{BuildError, Chara};
+use crate::job::{Job, JobCategory};
+use crate::race::Race;
+use crate::status::StatusKind;
+
+use enum_map::EnumMap;
+use strum::VariantArray;
+
+const MAX_LV: i32 = 99;
+const MAX_MASTER_LV: i32 = 50;
+
+/// レベル × マスターレベルごとの HP (サポートジョブなし・メリット/装備なし)。
+/// 行 = レベル (index 0 → Lv1 … index 98 → Lv99)、列 = マスターレベル (index 0 → ML0 … index 50 → ML50)。
+pub fn hp_heatmap(race: Race, job: Job) -> Vec<Vec<i32>> {
+    (1..=MAX_LV)
+        .map(|lv| {
+            (0..=MAX_MASTER_LV)
+                .map(|ml| {
+                    Chara::builder()
+                        .race(race)
+                        .main_job(job, lv)
+                        .master_lv(ml)
+                        .build()
+                        .expect("Lv1-99 and ML0-50 are always in range")
+                        .status(StatusKind::Hp)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 指定種族・レベル (サポートなし・ML0) で各ジョブのステータス値を返す (Job 定義順)。
+/// lv が範囲外なら `BuildError`
+fn stats_by_job(race: Race, kind: StatusKind, lv: i32) -> Result<Vec<i32>, BuildError> {
+    Job::VARIANTS
+        .iter()
+        .map(|&job| {
+            let chara = Chara::builder()
+                .race(race)
+                .main_job(job, lv)
+                .master_lv(0)
+                .build()?;
+            Ok(chara.status(kind))
+        })
+        .collect()
+}
+
+/// 全 22 ジョブにおけるステータスの平均値 (サポートなし・ML0)。
+/// MP を持たないジョブの MP は 0 として平均に含める。
+pub fn average_stat(race: Race, kind: StatusKind, lv: i32) -> Result<f32, BuildError> {
+    let values = stats_by_job(race, kind, lv)?;
+    Ok(values.iter().sum::<i32>() as f32 / values.len() as f32)
+}
+
+/// ジョブカテゴリごとのステータス平均値 (サポートなし・ML0)
+pub fn category_totals(
+    race: Race,
+    lv: i32,
+    kind: StatusKind,
+) -> Result<EnumMap<JobCategory, f32>, BuildError> {
+    let values = stats_by_job(race, kind, lv)?;
+    let mut sums: EnumMap<JobCategory, (i32, i32)> = EnumMap::default();
+    for (&job, value) in Job::VARIANTS.iter().zip(values) {
+        let (sum, count) = &mut sums[job.category()];
+        *sum += value;
+        *count += 1;
+    }
+    Ok(EnumMap::from_fn(|category| {
+        let (sum, count) = sums[category];
+        sum as f32 / count as f32
+    }))
+}
+
+/// 全 22 ジョブ中での偏差値 (平均 50・標準偏差 10)。
+/// 全ジョブが同値 (標準偏差 0) の場合は 50 を返す。
+pub fn stat_deviation(race: Race, job: Job, kind: StatusKind, lv: i32) -> Result<f32, BuildError> {
+    let values = stats_by_job(race, kind, lv)?;
+    let avg = average_stat(race, kind, lv)?;
+    let variance = values
+        .iter()
+        .map(|&v| (v as f32 - avg).powi(2))
+        .sum::<f32>()
+        / values.len() as f32;
+    let sd = variance.sqrt();
+    if sd == 0.0 {
+        return Ok(50.0);
+    }
+    let value = values[job as usize] as f32;
+    Ok(50.0 + 10.0 * (value - avg) / sd)
+}
+
+/// 全種族 × 全ジョブ (サポートなし) で指定ステータスが最大となる組み合わせ。
+/// 同値の場合は Race → Job の定義順で先に現れる組み合わせを返す。lv/ml が範囲外なら `BuildError`。
+pub fn find_max_stat(kind: StatusKind, lv: i32, ml: i32) -> Result<(Race, Job, i32), BuildError> {
+    let mut best: Option<(Race, Job, i32)> = None;
+    for &race in Race::VARIANTS {
+        for &job in Job::VARIANTS {
+            let value = Chara::builder()
+                .race(race)
+                .main_job(job, lv)
+                .master_lv(ml)
+                .build()?
+                .status(kind);
+            if best.is_none_or(|(_, _, v)| value > v) {
+                best = Some((race, job, value));
+            }
+        }
+    }
+    Ok(best.expect("Race and Job have at least one variant"))
+}
+
+/// 全種族 × 全ジョブ (サポートなし・ML0) で `kind` が最大となる構成。
+/// `find_max_stat(kind, lv, 0)` と同じ。
+pub fn max_status_build(kind: StatusKind, lv: i32) -> Result<(Race, Job, i32), BuildError> {
+    find_max_stat(kind, lv, 0)
+}
+
+/// 種族 (Race 定義順) とジョブ (Job 定義順) のグレード表を CSV で出力する。
+/// 1 行目はヘッダ (`name,HP,MP,...`)、以降は 1 行 1 種族/ジョブ。グレードなし (None) は空セル。
+pub fn export_grade_tables_csv() -> String {
+    let mut out = String::from("name");
+    for &kind in StatusKind::VARIANTS {
+        out.push_str(&format!(",{}", format!("{:?}", kind).to_uppercase()));
+    }
+    out.push('\n');
+
+    let rows = Race::VARIANTS
+        .iter()
+        .map(|race| {
+            let grades = StatusKind::VARIANTS
+                .iter()
+                .map(|&kind| Some(race.status_grade(kind)))
+                .collect::<Vec<_>>();
+            (format!("{:?}", race), grades)
+        })
+        .chain(Job::VARIANTS.iter().map(|job| {
+            let grades = StatusKind::VARIANTS
+                .iter()
+                .map(|&kind| job.status_grade(kind))
+                .collect::<Vec<_>>();
+            (format!("{:?}", job), grades)
+        }));
+    for (name, grades) in rows {
+        out.push_str(&name);
+        for grade in grades {
+            out.push(',');
+            if let Some(grade) = grade {
+                out.push_str(&format!("{:?}", grade));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// 複数ビルドのステータスを列に並べた Markdown の表 (列 = ビルド名、行 = StatusKind)
+pub fn comparison_table(builds: &[(String, Chara)]) -> String {
+    let mut out = String::from("| Status |");
+    for (name, _) in builds {
+        out.push_str(&format!(" {} |", name));
+    }
+    out.push_str("\n|---|");
+    out.push_str(&"---:|".repeat(builds.len()));
+    out.push('\n');
+
+    let statuses: Vec<_> = builds.iter().map(|(_, chara)| chara.status_map()).collect();
+    for &kind in StatusKind::VARIANTS {
+        out.push_str(&format!("| {} |", format!("{:?}", kind).to_uppercase()));
+        for status in &statuses {
+            out.push_str(&format!(" {} |", status[kind]));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hp_heatmap_shape() {
+        let map = hp_heatmap(Race::Hum, Job::War);
+        assert_eq!(map.len(), 99);
+        assert!(map.iter().all(|row| row.len() == 51));
+        // Hum/War99 ML0 = 1340 (既存テストと一致)
+        assert_eq!(map[98][0], 1340);
+        // ML50 で +7 × 50 = +350
+        assert_eq!(map[98][50], 1340 + 350);
+    }
+
+    #[test]
+    fn test_average_stat_between_min_and_max() {
+        for race in Race::VARIANTS {
+            for kind in StatusKind::VARIANTS {
+                for lv in [1, 50, 75, 99] {
+                    let values = stats_by_job(*race, *kind, lv).unwrap();
+                    let avg = average_stat(*race, *kind, lv).unwrap();
+                    let min = *values.iter().min().unwrap() as f32;
+                    let max = *values.iter().max().unwrap() as f32;
+                    assert!(min <= avg && avg <= max, "{:?} {:?} Lv{}", race, kind, lv);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_category_totals() {
+        let int = category_totals(Race::Hum, 99, StatusKind::Int).unwrap();
+        assert!(int[JobCategory::Magic] > int[JobCategory::Melee]);
+        let str_ = category_totals(Race::Hum, 99, StatusKind::Str).unwrap();
+        assert!(str_[JobCategory::Melee] > str_[JobCategory::Magic]);
+
+        // Ranged (Rng/Cor) はその 2 ジョブの平均
+        let agi = category_totals(Race::Hum, 99, StatusKind::Agi).unwrap();
+        let values = stats_by_job(Race::Hum, StatusKind::Agi, 99).unwrap();
+        let expected = (values[Job::Rng as usize] + values[Job::Cor as usize]) as f32 / 2.0;
+        assert_eq!(agi[JobCategory::Ranged], expected);
+    }
+
+    #[test]
+    fn test_stat_deviation_average_job_is_about_50() {
+        let (race, kind, lv) = (Race::Hum, StatusKind::Str, 99);
+        let avg = average_stat(race, kind, lv).unwrap();
+        // 平均値に最も近いジョブの偏差値はほぼ 50
+        let nearest = *Job::VARIANTS
+            .iter()
+            .min_by(|a, b| {
+                let da = (stat_deviation(race, **a, kind, lv).unwrap() - 50.0).abs();
+                let db = (stat_deviation(race, **b, kind, lv).unwrap() - 50.0).abs();
+                da.total_cmp(&db)
+            })
+            .unwrap();
+        let value = stats_by_job(race, kind, lv).unwrap()[nearest as usize] as f32;
+        assert!((value - avg).abs() < 2.0);
+        assert!((stat_deviation(race, nearest, kind, lv).unwrap() - 50.0).abs() < 5.0);
+
+        // 全ジョブの偏差値の平均は 50
+        let mean = Job::VARIANTS
+            .iter()
+            .map(|&job| stat_deviation(race, job, kind, lv).unwrap())
+            .sum::<f32>()
+            / Job::VARIANTS.len() as f32;
+        assert!((mean - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_stat_deviation_order() {
+        // War の STR は Blm より偏差値が高い
+        let war = stat_deviation(Race::Hum, Job::War, StatusKind::Str, 99).unwrap();
+        let blm = stat_deviation(Race::Hum, Job::Blm, StatusKind::Str, 99).unwrap();
+        assert!(war > 50.0);
+        assert!(war > blm);
+    }
+
+    #[test]
+    fn test_find_max_stat() {
+        let (race, _, hp) = find_max_stat(StatusKind::Hp, 99, 50).unwrap();
+        assert_eq!(race, Race::Gal);
+        let (race, _, mp) = find_max_stat(StatusKind::Mp, 99, 50).unwrap();
+        assert_eq!(race, Race::Tar);
+
+        // 返り値は全組み合わせの最大値
+        for &job in Job::VARIANTS {
+            let chara = Chara::builder()
+                .race(Race::Hum)
+                .main_job(job, 99)
+                .master_lv(50)
+                .build()
+                .unwrap();
+            assert!(chara.status(StatusKind::Hp) <= hp);
+            assert!(chara.status(StatusKind::Mp) <= mp);
+        }
+
+        // 範囲外の lv/ml は panic せずエラー
+        assert_eq!(
+            find_max_stat(StatusKind::Hp, 100, 0),
+            Err(BuildError::MainLvOutOfRange)
+        );
+        assert_eq!(
+            find_max_stat(StatusKind::Hp, 99, 51),
+            Err(BuildError::MasterLvOutOfRange)
+        );
+        assert_eq!(
+            average_stat(Race::Hum, StatusKind::Str, 0),
+            Err(BuildError::MainLvOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_max_status_build() {
+        for &kind in StatusKind::VARIANTS {
+            assert_eq!(
+                max_status_build(kind, 99).unwrap(),
+                find_max_stat(kind, 99, 0).unwrap()
+            );
+        }
+        let (race, job, _) = max_status_build(StatusKind::Mp, 99).unwrap();
+        assert_eq!((race, job), (Race::Tar, Job::Smn));
+    }
+
+    #[test]
+    fn test_comparison_table() {
+        let build = |job| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(job, 99)
+                .master_lv(0)
+                .build()
+                .unwrap()
+        };
+        let table = comparison_table(&[
+            ("War".to_string(), build(Job::War)),
+            ("Blm".to_string(), build(Job::Blm)),
+        ]);
+        let lines: Vec<&str> = table.lines().collect();
+        // ヘッダ + 区切り + StatusKind 9 行
+        assert_eq!(lines.len(), 2 + 9);
+        assert_eq!(lines[0], "| Status | War | Blm |");
+        assert_eq!(lines[1], "|---|---:|---:|");
+        assert_eq!(lines[2], "| HP | 1340 | 810 |");
+        assert_eq!(lines[3], "| MP | 0 | 1160 |");
+        assert!(lines[4].starts_with("| STR |"));
+        assert!(lines[10].starts_with("| CHR |"));
+    }
+
+    #[test]
+    fn test_export_grade_tables_csv() {
+        let csv = export_grade_tables_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        // ヘッダ + 種族 5 + ジョブ 22
+        assert_eq!(lines.len(), 1 + Race::VARIANTS.len() + Job::VARIANTS.len());
+        // 名前列 + StatusKind 9 列
+        assert!(lines
+            .iter()
+            .all(|line| line.split(',').count() == 1 + StatusKind::VARIANTS.len()));
+        assert_eq!(lines[0], "name,HP,MP,STR,DEX,VIT,AGI,INT,MND,CHR");
+        assert_eq!(lines[1 + Race::Tar as usize], "Tar,G,A,F,D,E,C,A,E,D");
+        // War は MP グレードなし → 空セル
+        assert_eq!(lines[1 + Race::VARIANTS.len()], "War,B,,A,C,D,C,F,F,E");
+    }
+
+    // 計算式を変更した場合は `cargo insta review` で差分を確認してスナップショットを更新する
+    #[test]
+    fn snapshot_all_races_war99() {
+        let builds: Vec<(String, Chara)> = Race::VARIANTS
+            .iter()
+            .map(|&race| {
+                let chara = Chara::builder()
+                    .race(race)
+                    .main_job(Job::War, 99)
+                    .master_lv(0)
+                    .build()
+                    .unwrap();
+                (format!("{:?}", race), chara)
+            })
+            .collect();
+        insta::assert_snapshot!(comparison_table(&builds));
+    }
+
+    #[test]
+    fn snapshot_all_jobs_hum99() {
+        let builds: Vec<(String, Chara)> = Job::VARIANTS
+            .iter()
+            .map(|&job| {
+                let chara = Chara::builder()
+                    .race(Race::Hum)
+                    .main_job(job, 99)
+                    .master_lv(0)
+                    .build()
+                    .unwrap();
+                (format!("{:?}", job), chara)
+            })
+            .collect();
+        insta::assert_snapshot!(comparison_table(&builds));
+    }
+
+    #[test]
+    fn test_hp_heatmap_monotonic_in_master_lv() {
+        for race in [Race::Tar, Race::Gal] {
+            for job in [Job::War, Job::Blm, Job::Mnk] {
+                for row in hp_heatmap(race, job) {
+                    assert!(row.windows(2).all(|w| w[0] < w[1]));
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,27 @@
+use std::cmp::Ordering;
+use std::fmt;
 use std::option::Option;
 
+use enum_map::EnumMap;
+
 use crate::gift::Gift;
-use crate::job::{Job, JobTrait};
-use crate::job_points::JobPointCategories;
+use crate::job::{Job, JobTrait, STAT_TRAITS};
+use crate::job_points::{JobPointCategories, JobPointCategory};
+use crate::merit::status_merit;
+use crate::precomputed::{race_job_grades, race_job_status};
 use crate::race::Race;
-use crate::skills::CharacterSkills;
-use crate::status::{calc_master_lv_bonus, calc_status, BonusStats, MeritPoints, StatusKind};
+use crate::skills::{job_skill_cap, CharacterSkills, SkillKind};
+use crate::status::{
+    calc_master_lv_bonus, calc_status, calc_status_by_band, BonusStats, FormulaVersion, LevelBand,
+    MeritPoints, Status, StatusKind, VersionedStatus,
+};
+
+use strum::VariantArray;
 
-#[derive(Debug, Clone)]
+/// キャラクターの構成。
+/// `PartialEq`/`Eq`/`Hash` は構成フィールド (種族・ジョブ・レベル・メリット・装備など) の比較であり、
+/// 計算後のステータス (小数を含む計算結果) の比較ではない。ステータスでの比較は `cmp_by` を使う。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Chara {
     pub race: Race,
     pub main_job: Job,
@@ -21,6 +35,8 @@ pub struct Chara {
     pub job_points: JobPointCategories,
     /// キャラクター共通のスキル値
     pub skills: CharacterSkills,
+    /// サポートジョブの HP/MP の反映割合
+    pub support_hp_mp_model: SupportHpMpModel,
 }
 
 impl Chara {
@@ -28,52 +44,287 @@ impl Chara {
         CharaBuilder::default()
     }
 
+    /// ステータスの整数値。種族・メイン・サポートの `calc_status` (0.5 単位) を合算してから切り捨て、
+    /// マスターレベル・メリット・装備・特性を加算する。
     pub fn status(&self, kind: StatusKind) -> i32 {
-        // For MP: if main job has no MP, return 0 (no race/support/mlv contribution)
-        if kind == StatusKind::Mp && self.main_job.status_grade(StatusKind::Mp).is_none() {
+        let Some((status_grade, bonuses)) = self.status_parts(kind) else {
             return 0;
-        }
+        };
 
-        // Race status
-        let grade_race = self.race.status_grade(kind);
-        let status_race = calc_status(kind, grade_race, self.main_lv);
+        // 装備のマイナス補正などで負にならないよう、加算は飽和させて 0 を下限とする
+        bonuses
+            .into_iter()
+            .fold(status_grade.floor() as i32, i32::saturating_add)
+            .max(0)
+    }
 
-        // Main job status
-        let status_main_job = match self.main_job.status_grade(kind) {
-            Some(grade) => calc_status(kind, grade, self.main_lv),
-            None => 0.0,
+    /// `status` の floor 前の値。種族・メイン・サポートの `calc_status` の合算 (小数) に
+    /// マスターレベル・メリット・装備・特性の整数加算を足したもの。
+    /// MP を持たないメインジョブの MP は 0.0 を返す。
+    pub fn status_raw(&self, kind: StatusKind) -> f32 {
+        let Some((status_grade, bonuses)) = self.status_parts(kind) else {
+            return 0.0;
         };
+        // 整数加算は `status` と同じく飽和させて合算する
+        status_grade + bonuses.into_iter().fold(0, i32::saturating_add) as f32
+    }
 
-        // Support job status (calculated at support_lv, then halved)
-        let status_support_job = match (&self.support_job, &self.support_lv) {
-            (Some(job), Some(lv)) => match job.status_grade(kind) {
-                Some(grade) => calc_status(kind, grade, *lv) / 2.0,
-                None => 0.0,
-            },
-            _ => 0.0,
-        };
+    /// ステータスの内訳。(種族 + メイン + サポートの小数値, [ML, メリット, 装備, 特性])。
+    /// MP を持たないメインジョブの MP は `None`
+    fn status_parts(&self, kind: StatusKind) -> Option<(f32, [i32; 4])> {
+        let (grade_race, grade_main_job) = race_job_grades(self.race, self.main_job, kind);
+
+        // For MP: if main job has no MP, return 0 (no race/support/mlv contribution)
+        if kind == StatusKind::Mp && grade_main_job.is_none() {
+            return None;
+        }
+
+        // Race + main job status (Lv1-99 は事前計算テーブルを参照)
+        let status_race_main = race_job_status(self.race, self.main_job, kind, self.main_lv)
+            .unwrap_or_else(|| {
+                let status_race = calc_status(kind, grade_race, self.main_lv);
+                let status_main_job = match grade_main_job {
+                    Some(grade) => calc_status(kind, grade, self.main_lv),
+                    None => 0.0,
+                };
+                status_race + status_main_job
+            });
+
+        // Support job status (HP/MP を含む全項目)
+        let status_support_job = self.support_job_status(kind);
 
         // Master level bonus
         let mlv_bonus = calc_master_lv_bonus(kind, self.master_lv);
 
         // Merit point bonus
-        let merit_bonus = self.merit_points.status_bonus(kind);
+        let merit_bonus = self.merit_bonus(kind);
 
-        // Job trait bonus for HP/MP
-        let trait_hp_mp = match kind {
-            StatusKind::Hp => {
-                self.job_trait_total(JobTrait::MaxHpBoost)
-                    + self.job_trait_total(JobTrait::MaxHpBoost2)
-            }
-            StatusKind::Mp => self.job_trait_total(JobTrait::MaxMpBoost),
-            _ => 0,
+        // Job trait bonus (MaxHpBoost etc.)
+        let trait_stat: i32 = STAT_TRAITS
+            .iter()
+            .filter(|&&(_, target)| target == kind)
+            .map(|&(trait_kind, _)| self.job_trait_total(trait_kind))
+            .sum();
+
+        Some((
+            status_race_main + status_support_job,
+            [
+                mlv_bonus,
+                merit_bonus,
+                self.bonus_stats.get(kind),
+                trait_stat,
+            ],
+        ))
+    }
+
+    /// 振り分け済みのメリットポイント
+    pub fn merit_points(&self) -> &MeritPoints {
+        &self.merit_points
+    }
+
+    /// メリットポイントによるステータス加算 (`status()` に含まれる)
+    pub fn merit_bonus(&self, kind: StatusKind) -> i32 {
+        self.merit_points.status_bonus(kind)
+    }
+
+    /// サポートジョブによる寄与 (切り捨て前)。
+    /// HP/MP も他のステータスと同様に、サポートジョブのグレードを 1 段階下げて
+    /// `support_lv` で `calc_status` した値の半分を加算する
+    /// (HP/MP の割合は `support_hp_mp_model` で切り替えられる)。
+    /// HP/MP の 30+ 項も 1 段階下げたサポートジョブのグレード・`support_lv` で計算する
+    /// (メインLv基準ではなく、support_lv が 31 以上のときのみ加わる)。
+    /// サポートジョブが MP を持っていても、メインジョブが MP を持たない場合は
+    /// `status()` 側で MP 全体が 0 になる。
+    pub fn support_job_status(&self, kind: StatusKind) -> f32 {
+        match (&self.support_job, &self.support_lv) {
+            (Some(job), Some(lv)) => job
+                .status_grade_as_support(kind)
+                .map(|grade| calc_status(kind, grade, *lv) * self.support_ratio(kind))
+                .unwrap_or(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// サポートジョブのステータスを加算する割合
+    fn support_ratio(&self, kind: StatusKind) -> f32 {
+        match kind {
+            StatusKind::Hp | StatusKind::Mp => self.support_hp_mp_model.ratio(),
+            _ => 0.5,
+        }
+    }
+
+    /// 種族・メイン・サポートのグレードによるステータスを、レベル帯ごとの増分に分解する。
+    /// マスターレベル・メリット・装備・ジョブ特性による加算は含まない
+    /// (それらを足すと `status()` と一致する)。
+    pub fn status_by_band(&self, kind: StatusKind) -> EnumMap<LevelBand, i32> {
+        let mut result = EnumMap::default();
+        let (grade_race, grade_main_job) = race_job_grades(self.race, self.main_job, kind);
+        if kind == StatusKind::Mp && grade_main_job.is_none() {
+            return result;
+        }
+
+        let race = calc_status_by_band(kind, grade_race, self.main_lv);
+        let main = grade_main_job
+            .map(|grade| calc_status_by_band(kind, grade, self.main_lv))
+            .unwrap_or_default();
+        let support = match (&self.support_job, &self.support_lv) {
+            (Some(job), Some(lv)) => job
+                .status_grade_as_support(kind)
+                .map(|grade| calc_status_by_band(kind, grade, *lv))
+                .unwrap_or_default(),
+            _ => EnumMap::default(),
         };
 
-        (status_race + status_main_job + status_support_job).floor() as i32
-            + mlv_bonus
-            + merit_bonus
-            + self.bonus_stats.get(kind)
-            + trait_hp_mp
+        // status() は合計を 1 回だけ切り捨てるので、累積値の切り捨て差分を各帯に割り当てる
+        let (mut total, mut prev) = (0.0, 0);
+        for &band in LevelBand::VARIANTS {
+            total += race[band] + main[band] + support[band] * self.support_ratio(kind);
+            let floored = total.floor() as i32;
+            result[band] = floored - prev;
+            prev = floored;
+        }
+        result
+    }
+
+    /// 種族だけを差し替えたクローン (レベル・メリット・装備などは維持)
+    pub fn with_race(&self, race: Race) -> Chara {
+        Chara {
+            race,
+            ..self.clone()
+        }
+    }
+
+    /// メインジョブだけを差し替えたクローン (メインLv・サポート・メリット・装備などは維持)
+    pub fn with_main_job(&self, job: Job) -> Chara {
+        Chara {
+            main_job: job,
+            ..self.clone()
+        }
+    }
+
+    /// 全ステータスを `Status` として計算する
+    pub fn to_status(&self) -> Status {
+        Status::from_fn(|kind| self.status(kind))
+    }
+
+    /// 装備・食事・バフ (`bonus_stats`) を除いた素の全ステータス。
+    /// 種族・メイン/サポートジョブ・マスターレベル・メリット・ジョブ特性は含む。ビルド比較のベースライン用
+    pub fn naked_status(&self) -> Status {
+        Chara {
+            bonus_stats: BonusStats::default(),
+            ..self.clone()
+        }
+        .to_status()
+    }
+
+    /// main_lv を 1〜99 に振ったときの全ステータス推移 (種族・ジョブ・ML・メリット・装備は固定)。
+    /// サポートジョブは現在の support_lv を実レベルとみなし、各レベルで
+    /// `effective_support_level` によるキャップを適用する (キャップが 0 以下のレベルではサポートなし)。
+    pub fn status_table(&self) -> Vec<(i32, Status)> {
+        (1..=99)
+            .map(|lv| {
+                let support_lv = self
+                    .support_lv
+                    .map(|actual| effective_support_level(actual, lv, self.master_lv))
+                    .filter(|&lv| lv > 0);
+                let chara = Chara {
+                    main_lv: lv,
+                    support_job: self.support_job.filter(|_| support_lv.is_some()),
+                    support_lv,
+                    ..self.clone()
+                };
+                (lv, chara.to_status())
+            })
+            .collect()
+    }
+
+    /// 全ステータスを計算式バージョン付きで返す
+    pub fn versioned_status(&self) -> VersionedStatus {
+        VersionedStatus {
+            formula_version: FormulaVersion::CURRENT,
+            status: self.to_status(),
+        }
+    }
+
+    /// フォーラムやチャットに貼り付けるためのコンパクトな 2 行表記 (区切りは全角スペース)。
+    /// 1 行目: 種族・ジョブ/レベル・ML、2 行目: HP〜CHR (MP を持たないジョブの MP は "-")
+    /// 例: "ヒューム　戦士99/竜騎士59　ML50\nHP1915　MP-　STR146　…"
+    pub fn to_clipboard_text(&self) -> String {
+        let mut jobs = format!("{}{}", self.main_job.name_ja(), self.main_lv);
+        if let (Some(job), Some(lv)) = (self.support_job, self.support_lv) {
+            jobs.push_str(&format!("/{}{}", job.name_ja(), lv));
+        }
+        let header = format!("{}　{}　ML{}", self.race.name_ja(), jobs, self.master_lv);
+
+        let stats: Vec<String> = StatusKind::VARIANTS
+            .iter()
+            .map(|&kind| {
+                let name = format!("{:?}", kind).to_uppercase();
+                if kind == StatusKind::Mp && self.main_job.status_grade(kind).is_none() {
+                    format!("{}-", name)
+                } else {
+                    format!("{}{}", name, self.status(kind))
+                }
+            })
+            .collect();
+        format!("{}\n{}", header, stats.join("　"))
+    }
+
+    /// 全ステータスをまとめて計算する
+    pub fn status_map(&self) -> EnumMap<StatusKind, i32> {
+        EnumMap::from_fn(|kind| self.status(kind))
+    }
+
+    /// 種族だけを差し替えた全種族の `status_map` (ジョブ・レベル・メリット・装備などは維持)
+    pub fn compare_races(&self) -> EnumMap<Race, EnumMap<StatusKind, i32>> {
+        EnumMap::from_fn(|race| self.with_race(race).status_map())
+    }
+
+    /// STR〜CHR の 7 ステータス合計
+    pub fn total_bp(&self) -> i32 {
+        self.status_map()
+            .iter()
+            .filter(|(kind, _)| !matches!(kind, StatusKind::Hp | StatusKind::Mp))
+            .map(|(_, v)| v)
+            .sum()
+    }
+
+    /// 各ステータスの差分 (`self - other`)
+    pub fn diff(&self, other: &Chara) -> EnumMap<StatusKind, i32> {
+        let (lhs, rhs) = (self.status_map(), other.status_map());
+        EnumMap::from_fn(|kind| lhs[kind] - rhs[kind])
+    }
+
+    /// 指定ステータスの値で比較する。
+    /// 例: `charas.sort_by(|a, b| b.cmp_by(a, StatusKind::Str))` で STR 降順
+    pub fn cmp_by(&self, other: &Chara, kind: StatusKind) -> Ordering {
+        self.status(kind).cmp(&other.status(kind))
+    }
+
+    /// HP + MP の合計
+    pub fn total_hpmp(&self) -> i32 {
+        let status = self.status_map();
+        status[StatusKind::Hp] + status[StatusKind::Mp]
+    }
+
+    /// 魔法スキルのキャップ (メリット込み)。魔法スキル以外は 0。
+    /// サポートジョブ由来のキャップは `support_lv` (メインLv/2 + ML/5 で頭打ち済み) で計算するため、
+    /// メインジョブの約半分で頭打ちになる (`skills::effective_skill` と同じ扱い)。
+    pub fn magic_skill_cap(&self, skill: SkillKind) -> i32 {
+        if !skill.is_magic() {
+            return 0;
+        }
+        let main_cap = job_skill_cap(self.main_job, skill, self.main_lv, self.master_lv);
+        let support_cap = match (self.support_job, self.support_lv) {
+            (Some(job), Some(lv)) => job_skill_cap(job, skill, lv, 0),
+            _ => 0,
+        };
+        let cap = main_cap.max(support_cap);
+        if cap > 0 {
+            cap + skill.merit_bonus(&self.merit_points, skill.key())
+        } else {
+            0
+        }
     }
 
     /// Calculate total job trait bonus from main + support job.
@@ -96,8 +347,33 @@ impl Chara {
         }
     }
 
+    /// 習得しているジョブ特性を (特性, ランク, 習得元) で返す (JobTrait 定義順、同じ特性はメインが先)。
+    /// メインは BLU の JobTraitEffectUp ギフトによるランクアップ込み、サポートはサポートレベルで判定する。
+    /// メインとサポートの両方で習得している特性は両方を返す (`job_trait_total` では強い方のみ有効)。
+    pub fn active_traits(&self) -> Vec<(JobTrait, u8, TraitSource)> {
+        let mut traits = Vec::new();
+        for &trait_kind in JobTrait::VARIANTS {
+            let main_rank = self.main_job_trait_rank(trait_kind);
+            if main_rank > 0 {
+                traits.push((trait_kind, main_rank as u8, TraitSource::Main));
+            }
+            if let (Some(job), Some(lv)) = (self.support_job, self.support_lv) {
+                let support_rank = job.trait_rank_at_lv(trait_kind, lv);
+                if support_rank > 0 {
+                    traits.push((trait_kind, support_rank as u8, TraitSource::Support));
+                }
+            }
+        }
+        traits
+    }
+
     /// メインジョブ単独のジョブ特性ボーナス (BLU の JobTraitEffectUp ギフトを考慮)。
     fn main_job_trait_bonus(&self, trait_kind: JobTrait) -> i32 {
+        trait_kind.value_at_rank(self.main_job_trait_rank(trait_kind))
+    }
+
+    /// メインジョブ単独のジョブ特性ランク (BLU の JobTraitEffectUp ギフトを考慮)。
+    fn main_job_trait_rank(&self, trait_kind: JobTrait) -> usize {
         let base_rank = self.main_job.trait_rank_at_lv(trait_kind, self.main_lv);
         if base_rank == 0 {
             // 未習得特性にはギフトのランクアップは適用されない
@@ -112,10 +388,87 @@ impl Chara {
         } else {
             0
         };
-        trait_kind.value_at_rank(base_rank + bonus_rank)
+        base_rank + bonus_rank
     }
 }
 
+/// サポートジョブの実効レベル: min(実レベル, メインLv/2 + マスターLv/5)
+pub fn effective_support_level(actual_lv: i32, main_lv: i32, master_lv: i32) -> i32 {
+    effective_support_level_with(
+        actual_lv,
+        main_lv,
+        master_lv,
+        SupportLevelRounding::default(),
+    )
+}
+
+/// `Chara::active_traits` のジョブ特性の習得元
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraitSource {
+    Main,
+    Support,
+}
+
+/// サポートジョブの HP/MP を `status()` にどれだけ反映するか (仕様検証用)。
+/// HP/MP 以外のステータスは常に半分を加算する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SupportHpMpModel {
+    /// サポートジョブの値をそのまま加算
+    Full,
+    /// 半分を加算 (現行)
+    #[default]
+    Half,
+    /// 加算しない
+    None,
+}
+
+impl SupportHpMpModel {
+    pub fn ratio(self) -> f32 {
+        match self {
+            SupportHpMpModel::Full => 1.0,
+            SupportHpMpModel::Half => 0.5,
+            SupportHpMpModel::None => 0.0,
+        }
+    }
+}
+
+/// 実効サポートレベル計算での除算の端数処理 (仕様検証用)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SupportLevelRounding {
+    /// 切り捨て (現行)
+    #[default]
+    Floor,
+    /// 切り上げ
+    Ceil,
+    /// 四捨五入
+    Round,
+}
+
+impl SupportLevelRounding {
+    /// `lhs / rhs` (いずれも 0 以上) をこの方式で整数に丸める
+    fn div(self, lhs: i32, rhs: i32) -> i32 {
+        match self {
+            SupportLevelRounding::Floor => lhs / rhs,
+            SupportLevelRounding::Ceil => (lhs + rhs - 1) / rhs,
+            SupportLevelRounding::Round => (2 * lhs + rhs) / (2 * rhs),
+        }
+    }
+}
+
+/// 端数処理を指定した実効サポートレベル: min(実レベル, メインLv/2 + マスターLv/5)。
+/// 2 つの除算それぞれに `rounding` を適用する。
+pub fn effective_support_level_with(
+    actual_lv: i32,
+    main_lv: i32,
+    master_lv: i32,
+    rounding: SupportLevelRounding,
+) -> i32 {
+    std::cmp::min(
+        actual_lv,
+        rounding.div(main_lv, 2) + rounding.div(master_lv, 5),
+    )
+}
+
 #[derive(Default)]
 pub struct CharaBuilder {
     race: Option<Race>,
@@ -123,11 +476,14 @@ pub struct CharaBuilder {
     main_lv: Option<i32>,
     support_job: Option<Job>,
     support_lv: Option<i32>,
+    /// `support_job_auto` で指定された (ジョブ, 実レベル)。キャップは build() 時に適用する
+    support_job_auto: Option<(Job, i32)>,
     master_lv: Option<i32>,
     merit_points: MeritPoints,
     bonus_stats: BonusStats,
     job_points: JobPointCategories,
     skills: CharacterSkills,
+    support_hp_mp_model: SupportHpMpModel,
 }
 
 impl CharaBuilder {
@@ -137,24 +493,29 @@ impl CharaBuilder {
     }
 
     pub fn main_job(mut self, job: Job, lv: i32) -> Self {
-        assert!(lv > 0 && lv <= 99, "main_lv must be between 1 and 99");
         self.main_job = Some(job);
         self.main_lv = Some(lv);
         self
     }
 
     pub fn support_job(mut self, job: Job, lv: i32) -> Self {
-        assert!(lv > 0 && lv <= 99, "support_lv must be between 1 and 99");
         self.support_job = Some(job);
         self.support_lv = Some(lv);
+        self.support_job_auto = None;
+        self
+    }
+
+    /// サポートジョブを実レベルで指定し、メインLv/マスターLv によるキャップを自動適用する。
+    /// 実効レベルは build() 時に `effective_support_level` で決まるため、
+    /// main_job / master_lv が未指定のまま build() するとエラーになる。
+    pub fn support_job_auto(mut self, job: Job, actual_lv: i32) -> Self {
+        self.support_job = None;
+        self.support_lv = None;
+        self.support_job_auto = Some((job, actual_lv));
         self
     }
 
     pub fn master_lv(mut self, master_lv: i32) -> Self {
-        assert!(
-            master_lv >= 0 && master_lv <= 50,
-            "master_lv must be between 0 and 50"
-        );
         self.master_lv = Some(master_lv);
         self
     }
@@ -174,27 +535,142 @@ impl CharaBuilder {
         self
     }
 
+    /// カテゴリ別の振り分けでジョブポイントを指定する
+    pub fn job_point_allocation(mut self, allocation: EnumMap<JobPointCategory, i32>) -> Self {
+        self.job_points = JobPointCategories::from_allocation(allocation);
+        self
+    }
+
     pub fn skills(mut self, skills: CharacterSkills) -> Self {
         self.skills = skills;
         self
     }
 
-    pub fn build(self) -> Result<Chara, &'static str> {
+    pub fn support_hp_mp_model(mut self, model: SupportHpMpModel) -> Self {
+        self.support_hp_mp_model = model;
+        self
+    }
+
+    /// レベル・メリットの範囲外指定は panic せず build() の Err として返す
+    pub fn build(self) -> Result<Chara, BuildError> {
+        if self.main_lv.is_some_and(|lv| !(1..=99).contains(&lv)) {
+            return Err(BuildError::MainLvOutOfRange);
+        }
+        let support_lv = self.support_lv.or(self.support_job_auto.map(|(_, lv)| lv));
+        if support_lv.is_some_and(|lv| !(1..=99).contains(&lv)) {
+            return Err(BuildError::SupportLvOutOfRange);
+        }
+        if self.master_lv.is_some_and(|lv| !(0..=50).contains(&lv)) {
+            return Err(BuildError::MasterLvOutOfRange);
+        }
+        if StatusKind::VARIANTS
+            .iter()
+            .any(|&kind| !status_merit(kind).contains(self.merit_points.get(kind)))
+        {
+            return Err(BuildError::MeritRankOutOfRange);
+        }
+
+        let (support_job, support_lv) = match self.support_job_auto {
+            Some((job, actual_lv)) => {
+                let main_lv = self.main_lv.ok_or(BuildError::SupportAutoWithoutMainJob)?;
+                let master_lv = self
+                    .master_lv
+                    .ok_or(BuildError::SupportAutoWithoutMasterLv)?;
+                let lv = effective_support_level(actual_lv, main_lv, master_lv);
+                if lv > 0 {
+                    (Some(job), Some(lv))
+                } else {
+                    (None, None)
+                }
+            }
+            None => (self.support_job, self.support_lv),
+        };
+
         Ok(Chara {
-            race: self.race.ok_or("race is required")?,
-            main_job: self.main_job.ok_or("main_job is required")?,
-            main_lv: self.main_lv.ok_or("main_lv is required")?,
-            support_job: self.support_job,
-            support_lv: self.support_lv,
-            master_lv: self.master_lv.ok_or("master_lv is required")?,
+            race: self.race.ok_or(BuildError::MissingRace)?,
+            main_job: self.main_job.ok_or(BuildError::MissingMainJob)?,
+            main_lv: self.main_lv.ok_or(BuildError::MissingMainLv)?,
+            support_job,
+            support_lv,
+            master_lv: self.master_lv.ok_or(BuildError::MissingMasterLv)?,
             merit_points: self.merit_points,
             bonus_stats: self.bonus_stats,
             job_points: self.job_points,
             skills: self.skills,
+            support_hp_mp_model: self.support_hp_mp_model,
         })
     }
 }
 
+/// `CharaBuilder::build` のエラー
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VariantArray)]
+pub enum BuildError {
+    MainLvOutOfRange,
+    SupportLvOutOfRange,
+    MasterLvOutOfRange,
+    MeritRankOutOfRange,
+    SupportAutoWithoutMainJob,
+    SupportAutoWithoutMasterLv,
+    MissingRace,
+    MissingMainJob,
+    MissingMainLv,
+    MissingMasterLv,
+}
+
+impl BuildError {
+    /// 範囲外指定によるエラーか (それ以外は必須項目の未指定)
+    pub fn is_out_of_range(self) -> bool {
+        matches!(
+            self,
+            BuildError::MainLvOutOfRange
+                | BuildError::SupportLvOutOfRange
+                | BuildError::MasterLvOutOfRange
+                | BuildError::MeritRankOutOfRange
+        )
+    }
+
+    /// エラーの原因となった入力項目
+    pub fn field(self) -> &'static str {
+        match self {
+            BuildError::MainLvOutOfRange | BuildError::MissingMainLv => "main_lv",
+            BuildError::SupportLvOutOfRange => "support_lv",
+            BuildError::MasterLvOutOfRange
+            | BuildError::SupportAutoWithoutMasterLv
+            | BuildError::MissingMasterLv => "master_lv",
+            BuildError::MeritRankOutOfRange => "merit_points",
+            BuildError::SupportAutoWithoutMainJob | BuildError::MissingMainJob => "main_job",
+            BuildError::MissingRace => "race",
+        }
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            BuildError::MainLvOutOfRange => "main_lv must be between 1 and 99",
+            BuildError::SupportLvOutOfRange => "support_lv must be between 1 and 99",
+            BuildError::MasterLvOutOfRange => "master_lv must be between 0 and 50",
+            BuildError::MeritRankOutOfRange => "merit point rank must be between 0 and 15",
+            BuildError::SupportAutoWithoutMainJob => "support_job_auto requires main_job",
+            BuildError::SupportAutoWithoutMasterLv => "support_job_auto requires master_lv",
+            BuildError::MissingRace => "race is required",
+            BuildError::MissingMainJob => "main_job is required",
+            BuildError::MissingMainLv => "main_lv is required",
+            BuildError::MissingMasterLv => "master_lv is required",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// `Result<_, String>` を返す関数から `?` で伝播できるようにする
+impl From<BuildError> for String {
+    fn from(e: BuildError) -> Self {
+        e.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,7 +697,7 @@ mod tests {
     fn test_chara_builder_missing_required_fields() {
         let result = Chara::builder().race(Race::Hum).build();
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "main_job is required");
+        assert_eq!(result.unwrap_err(), BuildError::MissingMainJob);
     }
 
     #[test]
@@ -237,12 +713,284 @@ mod tests {
         assert_eq!(chara.support_lv, None);
     }
 
+    #[test]
+    fn test_effective_support_level_rounding() {
+        use SupportLevelRounding::*;
+        // 既定は切り捨て
+        assert_eq!(effective_support_level(99, 99, 0), 49);
+        assert_eq!(effective_support_level_with(99, 99, 0, Floor), 49);
+
+        // Lv99 ML0: 99/2 = 49.5
+        assert_eq!(effective_support_level_with(99, 99, 0, Ceil), 50);
+        assert_eq!(effective_support_level_with(99, 99, 0, Round), 50);
+        // Lv99 ML2: 49.5 + 0.4
+        assert_eq!(effective_support_level_with(99, 99, 2, Floor), 49);
+        assert_eq!(effective_support_level_with(99, 99, 2, Ceil), 51);
+        assert_eq!(effective_support_level_with(99, 99, 2, Round), 50);
+        // Lv99 ML3: 49.5 + 0.6
+        assert_eq!(effective_support_level_with(99, 99, 3, Round), 51);
+        // 割り切れる場合はどの方式も同じ
+        for rounding in [Floor, Ceil, Round] {
+            assert_eq!(effective_support_level_with(99, 98, 50, rounding), 59);
+            // 実レベルが上限
+            assert_eq!(effective_support_level_with(30, 99, 50, rounding), 30);
+        }
+    }
+
+    #[test]
+    fn test_chara_builder_support_job_auto_capped() {
+        // メインLv99 / ML50 → キャップ 59
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(50)
+            .support_job_auto(Job::Drg, 99)
+            .build()
+            .unwrap();
+        assert_eq!(chara.support_job, Some(Job::Drg));
+        assert_eq!(chara.support_lv, Some(59));
+
+        // メインLv75 / ML0 → キャップ 37
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 75)
+            .master_lv(0)
+            .support_job_auto(Job::Drg, 99)
+            .build()
+            .unwrap();
+        assert_eq!(chara.support_lv, Some(37));
+    }
+
+    #[test]
+    fn test_chara_builder_support_job_auto_not_capped() {
+        // 実レベル 30 はキャップ 59 未満なのでそのまま使われる
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(50)
+            .support_job_auto(Job::Drg, 30)
+            .build()
+            .unwrap();
+        assert_eq!(chara.support_lv, Some(30));
+    }
+
+    #[test]
+    fn test_chara_builder_support_job_auto_without_main_job() {
+        let result = Chara::builder()
+            .race(Race::Hum)
+            .master_lv(50)
+            .support_job_auto(Job::Drg, 59)
+            .build();
+        assert_eq!(result.unwrap_err(), BuildError::SupportAutoWithoutMainJob);
+    }
+
+    #[test]
+    fn test_chara_builder_out_of_range_is_err() {
+        let base = || Chara::builder().race(Race::Hum).master_lv(0);
+        for lv in [0, 100, -1] {
+            assert_eq!(
+                base().main_job(Job::War, lv).build().unwrap_err(),
+                BuildError::MainLvOutOfRange
+            );
+            assert_eq!(
+                base()
+                    .main_job(Job::War, 99)
+                    .support_job(Job::Nin, lv)
+                    .build()
+                    .unwrap_err(),
+                BuildError::SupportLvOutOfRange
+            );
+            assert_eq!(
+                base()
+                    .main_job(Job::War, 99)
+                    .support_job_auto(Job::Nin, lv)
+                    .build()
+                    .unwrap_err(),
+                BuildError::SupportLvOutOfRange
+            );
+        }
+        assert_eq!(
+            base()
+                .main_job(Job::War, 99)
+                .master_lv(51)
+                .build()
+                .unwrap_err(),
+            BuildError::MasterLvOutOfRange
+        );
+        let merit_points = MeritPoints {
+            str_: 16,
+            ..Default::default()
+        };
+        assert_eq!(
+            base()
+                .main_job(Job::War, 99)
+                .merit_points(merit_points)
+                .build()
+                .unwrap_err(),
+            BuildError::MeritRankOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_build_error_message_and_field() {
+        // 文言は従来の &'static str のエラーと同じ
+        assert_eq!(
+            BuildError::MainLvOutOfRange.to_string(),
+            "main_lv must be between 1 and 99"
+        );
+        assert_eq!(
+            BuildError::SupportAutoWithoutMasterLv.to_string(),
+            "support_job_auto requires master_lv"
+        );
+        assert_eq!(BuildError::MeritRankOutOfRange.field(), "merit_points");
+        assert_eq!(BuildError::SupportAutoWithoutMainJob.field(), "main_job");
+        assert!(BuildError::MasterLvOutOfRange.is_out_of_range());
+        assert!(!BuildError::MissingRace.is_out_of_range());
+    }
+
+    #[test]
+    fn test_chara_versioned_status() {
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let versioned = chara.versioned_status();
+        assert_eq!(versioned.formula_version, FormulaVersion::CURRENT);
+        assert_eq!(versioned.status.hp, 1340);
+        assert_eq!(versioned.status.str, 82);
+
+        // 同じ値でも計算式バージョンが違えば区別される
+        let old = VersionedStatus {
+            formula_version: FormulaVersion::V1,
+            ..versioned.clone()
+        };
+        assert_eq!(old.status, versioned.status);
+        assert_ne!(old, versioned);
+    }
+
+    #[test]
+    fn test_chara_status_table() {
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Drg, 59)
+            .master_lv(50)
+            .build()
+            .unwrap();
+        let table = chara.status_table();
+        assert_eq!(table.len(), 99);
+        assert_eq!(table[0].0, 1);
+        let (lv, last) = table.last().unwrap();
+        assert_eq!(*lv, 99);
+        assert_eq!(*last, chara.to_status());
+
+        // Lv20 ではサポートは 20/2 + 50/5 = 20 にキャップされる
+        let lv20 = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 20)
+            .support_job(Job::Drg, 20)
+            .master_lv(50)
+            .build()
+            .unwrap();
+        assert_eq!(table[19].1, lv20.to_status());
+        // HP はレベルとともに単調増加
+        assert!(table.windows(2).all(|w| w[0].1.hp < w[1].1.hp));
+    }
+
+    #[test]
+    fn test_chara_with_race_and_main_job() {
+        let merit_points = MeritPoints {
+            hp: 5,
+            ..Default::default()
+        };
+        let hum_war = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .merit_points(merit_points.clone())
+            .build()
+            .unwrap();
+
+        let gal_war = hum_war.with_race(Race::Gal);
+        let expected = Chara::builder()
+            .race(Race::Gal)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .merit_points(merit_points.clone())
+            .build()
+            .unwrap();
+        assert_eq!(gal_war.race, Race::Gal);
+        assert_eq!(gal_war.main_lv, 99);
+        assert_eq!(gal_war.status_map(), expected.status_map());
+        assert!(gal_war.status(StatusKind::Hp) > hum_war.status(StatusKind::Hp));
+
+        let hum_blm = hum_war.with_main_job(Job::Blm);
+        let expected = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Blm, 99)
+            .master_lv(0)
+            .merit_points(merit_points)
+            .build()
+            .unwrap();
+        assert_eq!(hum_blm.status_map(), expected.status_map());
+        // 元の Chara は変更されない
+        assert_eq!(hum_war.main_job, Job::War);
+        assert_eq!(hum_war.status(StatusKind::Mp), 0);
+    }
+
+    #[test]
+    fn test_magic_skill_cap_support_is_about_half() {
+        let war_whm = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Whm, 49)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let whm = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Whm, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let sub = war_whm.magic_skill_cap(SkillKind::Healing);
+        let main = whm.magic_skill_cap(SkillKind::Healing);
+        assert!(sub > 0);
+        // Whm Lv49 相当 (150) vs Whm99 (424)
+        assert_eq!(sub, 150);
+        assert_eq!(main, 424);
+        assert!(sub < main / 2);
+
+        // サポートの実Lv はメインLv/2 + ML/5 で頭打ち (ML0 なら Whm49、ML50 なら Whm59)
+        let auto = |master_lv| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::War, 99)
+                .support_job_auto(Job::Whm, 99)
+                .master_lv(master_lv)
+                .build()
+                .unwrap()
+                .magic_skill_cap(SkillKind::Healing)
+        };
+        assert_eq!(auto(0), sub);
+        let whm59 = job_skill_cap(Job::Whm, SkillKind::Healing, 59, 0);
+        assert!(whm59 > sub);
+        assert_eq!(auto(50), whm59);
+
+        // 魔法スキル以外・未習得スキルは 0
+        assert_eq!(war_whm.magic_skill_cap(SkillKind::Sword), 0);
+        assert_eq!(war_whm.magic_skill_cap(SkillKind::Ninjutsu), 0);
+    }
+
     #[test]
     fn test_chara_status_war_drg() {
         // Hum/War99/Drg/MLV50
         // Support calc lv = 99/2 + 50/5 = 49 + 10 = 59
-        // HP = race(D:485) + job(B:675) + support(B@59:510/2=255) + mlv(350) + trait(180) = 1945
-        // STR = race(D:37.5) + job(A:45) + support(B@59:30/2=15) + mlv(50) = 147
+        // Support grade is one step down: Drg HP B→C, STR B→C
+        // HP = race(D:485) + job(B:675) + support(C@59:451/2=225.5) + mlv(350) + trait(180) = 1915
+        // STR = race(D:37.5) + job(A:45) + support(C@59:27/2=13.5) + mlv(50) = 146
         let chara = Chara::builder()
             .race(Race::Hum)
             .main_job(Job::War, 99)
@@ -251,12 +999,301 @@ mod tests {
             .build()
             .expect("Failed to build Chara");
 
-        assert_eq!(chara.status(StatusKind::Hp), 1945);
-        assert_eq!(chara.status(StatusKind::Str), 147);
+        assert_eq!(chara.status(StatusKind::Hp), 1915);
+        assert_eq!(chara.status(StatusKind::Str), 146);
         // War has no MP grade, so MP should be 0 (no MLV bonus either)
         assert_eq!(chara.status(StatusKind::Mp), 0);
     }
 
+    #[test]
+    fn test_chara_status_rounding_order() {
+        // Hum/War99 の DEX は種族 37.5・ジョブ 40.5 で、どちらも端数を持つ。
+        // 合算してから切り捨てると 78、グレードごとに切り捨ててから合算すると 77 になる
+        let race = calc_status(StatusKind::Dex, Race::Hum.status_grade(StatusKind::Dex), 99);
+        let job = calc_status(
+            StatusKind::Dex,
+            Job::War.status_grade(StatusKind::Dex).unwrap(),
+            99,
+        );
+        assert_eq!((race, job), (37.5, 40.5));
+        assert_eq!(
+            Race::Hum.status_value(StatusKind::Dex, 99)
+                + Job::War.status_value(StatusKind::Dex, 99).unwrap(),
+            77
+        );
+
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        assert_eq!(chara.status(StatusKind::Dex), 78);
+        assert_eq!(
+            chara.status_by_band(StatusKind::Dex).values().sum::<i32>(),
+            78
+        );
+    }
+
+    #[test]
+    fn test_chara_eq_and_cmp_by() {
+        use std::collections::HashSet;
+
+        let build = |race, job| {
+            Chara::builder()
+                .race(race)
+                .main_job(job, 99)
+                .master_lv(0)
+                .build()
+                .unwrap()
+        };
+        let war = build(Race::Hum, Job::War);
+        let blm = build(Race::Tar, Job::Blm);
+        let mnk = build(Race::Gal, Job::Mnk);
+
+        // 構成が同じなら等しく、同じハッシュになる
+        assert_eq!(war, build(Race::Hum, Job::War));
+        assert_ne!(war, blm);
+        let set: HashSet<Chara> = [war.clone(), war.clone(), blm.clone()].into();
+        assert_eq!(set.len(), 2);
+
+        // STR 降順
+        let mut charas: Vec<Chara> = [blm.clone(), war.clone(), mnk].to_vec();
+        charas.sort_by(|a, b| b.cmp_by(a, StatusKind::Str));
+        let strs: Vec<i32> = charas.iter().map(|c| c.status(StatusKind::Str)).collect();
+        assert!(strs.windows(2).all(|w| w[0] >= w[1]), "{strs:?}");
+        assert_eq!(charas.last(), Some(&blm));
+        assert_eq!(war.cmp_by(&war, StatusKind::Str), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_chara_compare_races() {
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Blm, 99)
+            .support_job(Job::Rdm, 49)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let by_race = chara.compare_races();
+        assert_eq!(by_race[Race::Hum], chara.status_map());
+        assert_eq!(by_race[Race::Tar], chara.with_race(Race::Tar).status_map());
+
+        let max_race = |kind| {
+            Race::VARIANTS
+                .iter()
+                .copied()
+                .max_by_key(|&race| by_race[race][kind])
+                .unwrap()
+        };
+        assert_eq!(max_race(StatusKind::Hp), Race::Gal);
+        assert_eq!(max_race(StatusKind::Mp), Race::Tar);
+    }
+
+    #[test]
+    fn test_chara_status_raw() {
+        // Hum/War99/Drg/MLV50: HP はサポートの 225.5 により小数部が残る
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Drg, 59)
+            .master_lv(50)
+            .build()
+            .unwrap();
+
+        assert_eq!(chara.status_raw(StatusKind::Hp), 1915.5);
+        let str_raw = chara.status_raw(StatusKind::Str);
+        assert!((146.0..147.0).contains(&str_raw), "STR raw = {str_raw}");
+        for kind in StatusKind::VARIANTS {
+            assert_eq!(
+                chara.status_raw(*kind).floor() as i32,
+                chara.status(*kind),
+                "{kind:?}"
+            );
+        }
+        assert_eq!(chara.status_raw(StatusKind::Mp), 0.0);
+    }
+
+    #[test]
+    fn test_chara_status_trait_stat_step() {
+        // War の HP は最大HPアップ習得 (Lv30) の前後で特性分の段差が出る
+        let hp_without_trait = |lv| {
+            (calc_status(StatusKind::Hp, Race::Hum.status_grade(StatusKind::Hp), lv)
+                + calc_status(
+                    StatusKind::Hp,
+                    Job::War.status_grade(StatusKind::Hp).unwrap(),
+                    lv,
+                ))
+            .floor() as i32
+        };
+        for lv in 25..=35 {
+            let chara = Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::War, lv)
+                .master_lv(0)
+                .build()
+                .unwrap();
+            assert_eq!(
+                chara.status(StatusKind::Hp),
+                hp_without_trait(lv) + Job::War.trait_stat_bonus(lv)[StatusKind::Hp],
+                "Lv{}",
+                lv
+            );
+        }
+        assert_eq!(Job::War.trait_stat_bonus(30)[StatusKind::Hp], 30);
+    }
+
+    #[test]
+    fn test_chara_status_by_band() {
+        let war_drg = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Drg, 59)
+            .master_lv(50)
+            .build()
+            .unwrap();
+        for &kind in StatusKind::VARIANTS {
+            let bands = war_drg.status_by_band(kind);
+            let others = if kind == StatusKind::Mp {
+                0
+            } else {
+                calc_master_lv_bonus(kind, 50) + Job::War.trait_stat_bonus(99)[kind]
+            };
+            assert_eq!(
+                bands.values().sum::<i32>() + others,
+                war_drg.status(kind),
+                "{:?}",
+                kind
+            );
+        }
+        // Lv99 は全帯で増分がある
+        let hp = war_drg.status_by_band(StatusKind::Hp);
+        assert!(LevelBand::VARIANTS.iter().all(|&band| hp[band] > 0));
+
+        // Lv60 では Lv61 以降の帯は 0
+        let blm60 = Chara::builder()
+            .race(Race::Tar)
+            .main_job(Job::Blm, 60)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        for &kind in StatusKind::VARIANTS {
+            let bands = blm60.status_by_band(kind);
+            assert_eq!(bands[LevelBand::To75], 0);
+            assert_eq!(bands[LevelBand::To99], 0);
+            assert_eq!(
+                bands.values().sum::<i32>(),
+                blm60.status(kind),
+                "{:?}",
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_chara_naked_status() {
+        let builder = || {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::War, 99)
+                .support_job(Job::Drg, 59)
+                .master_lv(50)
+        };
+        let naked = builder().build().unwrap();
+        let geared = builder()
+            .bonus_stats(BonusStats {
+                hp: 300,
+                str_: 40,
+                vit: -10,
+                ..BonusStats::default()
+            })
+            .build()
+            .unwrap();
+
+        assert_ne!(geared.to_status(), naked.to_status());
+        assert_eq!(geared.naked_status(), naked.to_status());
+        assert_eq!(geared.naked_status().hp, 1915);
+        assert_eq!(geared.naked_status().str, 146);
+    }
+
+    #[test]
+    fn test_chara_to_clipboard_text() {
+        let war_drg = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Drg, 59)
+            .master_lv(50)
+            .build()
+            .unwrap();
+        let text = war_drg.to_clipboard_text();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "ヒューム　戦士99/竜騎士59　ML50");
+        assert_eq!(
+            lines[1],
+            "HP1915　MP-　STR146　DEX138　VIT136　AGI138　INT125　MND126　CHR133"
+        );
+
+        let blm = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Blm, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        assert!(
+            blm.to_clipboard_text()
+                .starts_with("ヒューム　黒魔道士99　ML0\nHP810　MP1160　")
+        );
+    }
+
+    #[test]
+    fn test_chara_status_is_never_negative() {
+        // Tar の HP はグレード G。低レベル・サポートなしでも 0 未満にならない
+        for &job in Job::VARIANTS {
+            for lv in 1..=5 {
+                let chara = Chara::builder()
+                    .race(Race::Tar)
+                    .main_job(job, lv)
+                    .master_lv(0)
+                    .build()
+                    .unwrap();
+                assert!(chara.status(StatusKind::Hp) > 0, "{:?} Lv{}", job, lv);
+            }
+        }
+
+        // 装備のマイナス補正が素ステータスを上回る場合は 0 に張り付く
+        let bonus_stats = BonusStats {
+            hp: -10_000,
+            str_: -10_000,
+            ..Default::default()
+        };
+        let chara = Chara::builder()
+            .race(Race::Tar)
+            .main_job(Job::Blm, 1)
+            .master_lv(0)
+            .bonus_stats(bonus_stats)
+            .build()
+            .unwrap();
+        assert_eq!(chara.status(StatusKind::Hp), 0);
+        assert_eq!(chara.status(StatusKind::Str), 0);
+
+        // 極端な加算でもオーバーフローしない
+        let bonus_stats = BonusStats {
+            hp: i32::MAX,
+            ..Default::default()
+        };
+        let chara = Chara::builder()
+            .race(Race::Gal)
+            .main_job(Job::War, 99)
+            .master_lv(50)
+            .bonus_stats(bonus_stats)
+            .build()
+            .unwrap();
+        assert_eq!(chara.status(StatusKind::Hp), i32::MAX);
+        // status_raw も同じく飽和させる (debug ビルドでも panic しない)
+        assert!(chara.status_raw(StatusKind::Hp) >= i32::MAX as f32);
+    }
+
     #[test]
     fn test_chara_status_cor_sam() {
         // Gal/Cor99/Sam/MLV50
@@ -269,13 +1306,13 @@ mod tests {
             .build()
             .expect("Failed to build Chara");
 
-        assert_eq!(chara.status(StatusKind::Str), 138);
-        assert_eq!(chara.status(StatusKind::Dex), 141);
-        assert_eq!(chara.status(StatusKind::Vit), 143);
-        assert_eq!(chara.status(StatusKind::Agi), 138);
-        assert_eq!(chara.status(StatusKind::Int), 135);
-        assert_eq!(chara.status(StatusKind::Mnd), 132);
-        assert_eq!(chara.status(StatusKind::Chr), 127);
+        assert_eq!(chara.status(StatusKind::Str), 136);
+        assert_eq!(chara.status(StatusKind::Dex), 139);
+        assert_eq!(chara.status(StatusKind::Vit), 141);
+        assert_eq!(chara.status(StatusKind::Agi), 137);
+        assert_eq!(chara.status(StatusKind::Int), 133);
+        assert_eq!(chara.status(StatusKind::Mnd), 130);
+        assert_eq!(chara.status(StatusKind::Chr), 125);
     }
 
 
@@ -283,7 +1320,7 @@ mod tests {
     #[test]
     fn test_chara_status_blm_with_mp() {
         // Tar/Blm99/Rdm@59/MLV50
-        // Tar has MP grade A, Blm has MP grade B, Rdm has MP grade D
+        // Tar has MP grade A, Blm has MP grade B, Rdm has MP grade D (E as support)
         let chara = Chara::builder()
             .race(Race::Tar)
             .main_job(Job::Blm, 99)
@@ -295,8 +1332,178 @@ mod tests {
         // MP should be non-zero since Blm has MP
         assert!(chara.status(StatusKind::Mp) > 0);
         // MLV MP bonus should be applied (2 * 50 = 100)
-        // Tar(A:736) + Blm(B:675) + Rdm(D@59:362/2=181) + mlv(100) = 1692
-        assert_eq!(chara.status(StatusKind::Mp), 1692);
+        // Tar(A:736) + Blm(B:675) + Rdm(E@59:303/2=151.5) + mlv(100) = 1662
+        assert_eq!(chara.status(StatusKind::Mp), 1662);
+    }
+
+    #[test]
+    fn test_chara_support_hp_30plus_term() {
+        let build = |main, support: Job, lv| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(main, 99)
+                .support_job(support, lv)
+                .master_lv(50)
+                .build()
+                .unwrap()
+        };
+
+        // Mnk(HP A → サポート B) は 30+ 項の係数が 1 なので support_lv 基準で加算される
+        // Lv59: 17 + 8*58 + 30+(1*29) = 510 → /2 = 255
+        assert_eq!(
+            build(Job::War, Job::Mnk, 59).support_job_status(StatusKind::Hp),
+            255.0
+        );
+        // Lv30 では 30+ 項は 0: 17 + 8*29 = 249 → /2 = 124.5
+        assert_eq!(
+            build(Job::War, Job::Mnk, 30).support_job_status(StatusKind::Hp),
+            124.5
+        );
+
+        // Drg(HP B → サポート C) も 30+ 係数が 1: 16 + 7*58 + 30+(1*29) = 451 → /2 = 225.5
+        // Rdm(MP D → サポート E) の 30+ 係数は 0: 303 → /2 = 151.5
+        assert_eq!(build(Job::War, Job::Drg, 59).status(StatusKind::Hp), 1915);
+        let blm_rdm = Chara::builder()
+            .race(Race::Tar)
+            .main_job(Job::Blm, 99)
+            .support_job(Job::Rdm, 59)
+            .master_lv(50)
+            .build()
+            .unwrap();
+        assert_eq!(blm_rdm.support_job_status(StatusKind::Mp), 151.5);
+        assert_eq!(blm_rdm.status(StatusKind::Mp), 1662);
+    }
+
+    #[test]
+    fn test_chara_status_support_hp_mp() {
+        let build = |race, main, support: Option<(Job, i32)>| {
+            let mut builder = Chara::builder().race(race).main_job(main, 99).master_lv(50);
+            if let Some((job, lv)) = support {
+                builder = builder.support_job(job, lv);
+            }
+            builder.build().expect("Failed to build Chara")
+        };
+
+        // Tar/Blm99/Rdm@59: Rdm(E@59:303/2=151.5) の分だけ MP が増える
+        let blm = build(Race::Tar, Job::Blm, None);
+        let blm_rdm = build(Race::Tar, Job::Blm, Some((Job::Rdm, 59)));
+        assert_eq!(blm_rdm.support_job_status(StatusKind::Mp), 151.5);
+        assert_eq!(blm.status(StatusKind::Mp), 1511);
+        assert_eq!(blm_rdm.status(StatusKind::Mp), 1662);
+
+        // Hum/War99/Drg@59: Drg の HP も半分が加算される
+        let war = build(Race::Hum, Job::War, None);
+        let war_drg = build(Race::Hum, Job::War, Some((Job::Drg, 59)));
+        let diff = war_drg.status(StatusKind::Hp) - war.status(StatusKind::Hp);
+        assert!(diff > 0);
+        assert_eq!(
+            diff,
+            war_drg.support_job_status(StatusKind::Hp).floor() as i32
+        );
+        assert_eq!(war_drg.status(StatusKind::Hp), 1915);
+
+        // メインが MP を持たなければ、サポートに MP があっても 0
+        let war_blm = build(Race::Hum, Job::War, Some((Job::Blm, 59)));
+        assert!(war_blm.support_job_status(StatusKind::Mp) > 0.0);
+        assert_eq!(war_blm.status(StatusKind::Mp), 0);
+    }
+
+    #[test]
+    fn test_chara_status_support_hp_mp_model() {
+        // Tar/Blm99/Rdm@59/MLV50: Rdm(E@59) の MP は 303
+        let mp = |model| {
+            Chara::builder()
+                .race(Race::Tar)
+                .main_job(Job::Blm, 99)
+                .support_job(Job::Rdm, 59)
+                .master_lv(50)
+                .support_hp_mp_model(model)
+                .build()
+                .unwrap()
+                .status(StatusKind::Mp)
+        };
+        assert_eq!(SupportHpMpModel::default(), SupportHpMpModel::Half);
+        assert_eq!(mp(SupportHpMpModel::Half), 1662);
+        assert_eq!(mp(SupportHpMpModel::Full), 1511 + 303);
+        assert_eq!(mp(SupportHpMpModel::None), 1511);
+
+        // HP/MP 以外はモデルによらず半分
+        let str_ = |model| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::War, 99)
+                .support_job(Job::Drg, 59)
+                .master_lv(50)
+                .support_hp_mp_model(model)
+                .build()
+                .unwrap()
+                .status(StatusKind::Str)
+        };
+        assert_eq!(str_(SupportHpMpModel::Full), 146);
+        assert_eq!(str_(SupportHpMpModel::None), 146);
+    }
+
+    #[test]
+    fn test_chara_merit_points() {
+        let merit_points = MeritPoints {
+            hp: 5,
+            str_: 3,
+            ..Default::default()
+        };
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .merit_points(merit_points.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(chara.merit_points().hp, 5);
+        assert_eq!(chara.merit_points().str_, 3);
+        assert_eq!(chara.merit_bonus(StatusKind::Hp), 50);
+        assert_eq!(chara.merit_bonus(StatusKind::Str), 3);
+        assert_eq!(chara.merit_bonus(StatusKind::Dex), 0);
+        // HP = race(D:485) + job(B:675) + merit(5*10=50) + trait(180) = 1390
+        assert_eq!(chara.status(StatusKind::Hp), 1390);
+        // STR = race(D:37.5) + job(A:45) + merit(3*1=3) = 85
+        assert_eq!(chara.status(StatusKind::Str), 85);
+    }
+
+    #[test]
+    fn test_chara_active_traits() {
+        // War99/Nin49 (ML0): サポート忍者の二刀流は Lv45 の rank 3 まで
+        let war_nin = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job_auto(Job::Nin, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        assert_eq!(war_nin.support_lv, Some(49));
+        let traits = war_nin.active_traits();
+        assert!(traits.contains(&(JobTrait::DualWield, 3, TraitSource::Support)));
+        assert!(!traits.contains(&(JobTrait::DualWield, 3, TraitSource::Main)));
+        assert!(traits
+            .iter()
+            .any(|&(t, _, source)| t == JobTrait::DoubleAttack && source == TraitSource::Main));
+        // ランクは job_trait_total と整合する
+        assert_eq!(
+            war_nin.job_trait_total(JobTrait::DualWield),
+            JobTrait::DualWield.value_at_rank(3)
+        );
+
+        // サポートなしならメインの特性のみ
+        let nin = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Nin, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let traits = nin.active_traits();
+        assert!(traits.contains(&(JobTrait::DualWield, 5, TraitSource::Main)));
+        assert!(traits
+            .iter()
+            .all(|&(_, _, source)| source == TraitSource::Main));
     }
 
     #[test]
@@ -315,6 +1522,90 @@ mod tests {
         assert_eq!(chara.status(StatusKind::Str), 82);
     }
 
+    #[test]
+    fn test_chara_total_bp_and_hpmp() {
+        let war = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let blm = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Blm, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+
+        // BP 合計は同じだが配分が異なる (War は STR/VIT、Blm は INT/MND 寄り)
+        assert_eq!(war.total_bp(), 521);
+        assert_eq!(blm.total_bp(), 521);
+        let (war_map, blm_map) = (war.status_map(), blm.status_map());
+        assert!(war_map[StatusKind::Str] > blm_map[StatusKind::Str]);
+        assert!(war_map[StatusKind::Vit] > blm_map[StatusKind::Vit]);
+        assert!(war_map[StatusKind::Int] < blm_map[StatusKind::Int]);
+        assert!(war_map[StatusKind::Mnd] < blm_map[StatusKind::Mnd]);
+
+        // HP+MP: War 1340 + 0, Blm 810 + 1160
+        assert_eq!(war.total_hpmp(), 1340);
+        assert_eq!(blm.total_hpmp(), 1970);
+    }
+
+    #[test]
+    fn test_chara_diff() {
+        let build = |support_job: Job| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::War, 99)
+                .support_job(support_job, 59)
+                .master_lv(50)
+                .build()
+                .unwrap()
+        };
+        let war_drg = build(Job::Drg);
+        let war_sam = build(Job::Sam);
+
+        // 同一構成同士は全 0
+        assert!(war_drg.diff(&war_drg.clone()).values().all(|&v| v == 0));
+
+        // サポート違い: 差分 = 各 status の差
+        let diff = war_sam.diff(&war_drg);
+        for (kind, value) in diff {
+            assert_eq!(value, war_sam.status(kind) - war_drg.status(kind));
+        }
+        assert!(diff.values().any(|&v| v != 0));
+        // 逆方向は符号反転
+        let rev = war_drg.diff(&war_sam);
+        assert!(diff.iter().all(|(kind, &v)| rev[kind] == -v));
+    }
+
+    #[test]
+    fn test_chara_status_is_sum_of_race_and_job_values() {
+        // サポートなし・ML0 では合算結果 = 種族単独 + ジョブ単独 (+ 特性)
+        let war = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let race_hp = Race::Hum.status_value(StatusKind::Hp, 99);
+        let job_hp = Job::War.status_value(StatusKind::Hp, 99).unwrap();
+        assert_eq!(
+            war.status(StatusKind::Hp),
+            race_hp + job_hp + war.job_trait_total(JobTrait::MaxHpBoost)
+        );
+
+        let blm = Chara::builder()
+            .race(Race::Tar)
+            .main_job(Job::Blm, 75)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        let race_mp = Race::Tar.status_value(StatusKind::Mp, 75);
+        let job_mp = Job::Blm.status_value(StatusKind::Mp, 75).unwrap();
+        assert_eq!(blm.status(StatusKind::Mp), race_mp + job_mp);
+    }
+
     // -----------------------------------------------------------------------
     // BLU ギフト「ジョブ特性効果アップ」(https://wiki.ffo.jp/html/34014.html)
     // 100 JP = +1 rank, 1200 JP = +2 rank
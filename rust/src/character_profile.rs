@@ -1,26 +1,123 @@
+use std::fmt;
+
 use enum_map::EnumMap;
 use serde::{Deserialize, Serialize};
 
-use crate::chara::Chara;
+use crate::chara::{BuildError, Chara, effective_support_level};
 use crate::job::Job;
 use crate::job_points::JobPoints;
+use crate::merit::{status_merit, COMBAT_SKILL_MERIT, MAGIC_SKILL_MERIT};
 use crate::race::Race;
 use crate::skills::CharacterSkills;
-use crate::status::MeritPoints;
+use crate::status::{MeritPoints, StatusKind, VariantArray};
+
+/// `CharacterProfile` の JSON スキーマバージョン。
+/// フィールド構成を変更した場合はインクリメントし、`migrate_profile` に移行処理を追加する。
+/// - v0: バージョンフィールドなし (初期形式)
+/// - v1: `schema_version` を追加
+/// - v2: `merit_points` を省略可能に (欠落時は `MeritPoints::default()`)
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// `CharacterProfile::rank` の閾値: (ジョブレベル合計の下限, ランク名)。上から順に判定する
+pub const RANK_THRESHOLDS: [(i32, &str); 4] = [
+    (22 * 99, "マスター"),
+    (10 * 99, "ベテラン"),
+    (300, "一人前"),
+    (0, "新米"),
+];
+
+/// キャラクター名の正規化 (前後の空白を取り除く)。レジストリの重複判定・検索もこの名前で行う
+pub fn normalize_name(name: &str) -> &str {
+    name.trim()
+}
+
+/// `CharacterProfile::to_chara` / `to_chara_at` のエラー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileError {
+    /// 未習得 (レベル 0) のジョブを指定した。`support` はサポートジョブ側のとき true
+    NotLeveled { job: Job, support: bool },
+    /// `main_lv_override` が 1〜実レベル (`max`) の範囲外
+    MainLvOverrideOutOfRange { job: Job, max: i32, lv: i32 },
+    /// `CharaBuilder::build` のエラー
+    Build(BuildError),
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::NotLeveled {
+                job,
+                support: false,
+            } => write!(f, "{:?} is not leveled", job),
+            ProfileError::NotLeveled { job, support: true } => {
+                write!(f, "Support job {:?} is not leveled", job)
+            }
+            ProfileError::MainLvOverrideOutOfRange { job, max, lv } => write!(
+                f,
+                "main_lv_override must be between 1 and {} ({:?}): {}",
+                max, job, lv
+            ),
+            ProfileError::Build(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+impl From<BuildError> for ProfileError {
+    fn from(e: BuildError) -> Self {
+        ProfileError::Build(e)
+    }
+}
+
+/// `Result<_, String>` を返す関数から `?` で伝播できるようにする
+impl From<ProfileError> for String {
+    fn from(e: ProfileError) -> Self {
+        e.to_string()
+    }
+}
 
 /// ジョブごとのレベル情報
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JobLevel {
     pub level: i32,
     pub master_lv: i32,
 }
 
+/// `CharacterProfile::merge` でのジョブレベルの統合方法。
+/// いずれの戦略でも、取り込み元でレベル 0 (未習得) のジョブは情報なしとして扱い、統合先を変更しない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// レベル・マスターレベルそれぞれ高い方を採用
+    KeepHigher,
+    /// 取り込み元の値で上書き
+    Overwrite,
+    /// 統合先で習得済み (レベル 1 以上) のジョブは変更せず、未習得のジョブのみ取り込む
+    KeepExisting,
+}
+
+/// `CharacterProfile::summary` の集計結果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileSummary {
+    /// レベル 1 以上のジョブ数
+    pub leveled_jobs: usize,
+    /// レベル 99 のジョブ数
+    pub max_level_jobs: usize,
+    /// 全ジョブのマスターレベル合計
+    pub total_master_lv: i32,
+}
+
 /// キャラクタープロファイル（名前・種族・全ジョブのレベル情報・メリットポイント・ジョブポイント・スキル）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterProfile {
+    /// JSON スキーマバージョン (未指定の古い JSON は 0)
+    #[serde(default)]
+    pub schema_version: u32,
     pub name: String,
     pub race: Race,
-    pub job_levels: EnumMap<Job, JobLevel>,
+    /// 範囲外の値を書き込めないよう非公開。`job_level` / `try_set_job_level` 経由で扱う
+    job_levels: EnumMap<Job, JobLevel>,
+    #[serde(default)]
     pub merit_points: MeritPoints,
     #[serde(default)]
     pub job_points: JobPoints,
@@ -29,9 +126,11 @@ pub struct CharacterProfile {
 }
 
 impl CharacterProfile {
+    /// 名前の前後の空白は取り除く。空の名前も受け付ける (検証する場合は `try_new`)
     pub fn new(name: String, race: Race) -> Self {
         Self {
-            name,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            name: normalize_name(&name).to_string(),
             race,
             job_levels: EnumMap::default(),
             merit_points: MeritPoints::default(),
@@ -40,25 +139,334 @@ impl CharacterProfile {
         }
     }
 
-    pub fn set_job_level(&mut self, job: Job, level: i32, master_lv: i32) {
-        assert!(
-            level >= 0 && level <= 99,
-            "level must be between 0 and 99"
-        );
-        assert!(
-            master_lv >= 0 && master_lv <= 50,
-            "master_lv must be between 0 and 50"
-        );
+    /// `new` の検証版。前後の空白を取り除いた名前が空ならエラー
+    pub fn try_new(name: String, race: Race) -> Result<Self, String> {
+        if normalize_name(&name).is_empty() {
+            return Err("name must not be empty".to_string());
+        }
+        Ok(Self::new(name, race))
+    }
+
+    /// ジョブのレベル情報 (未習得ならレベル 0)。
+    ///
+    /// `job_levels` は非公開のため、範囲外の値を直接書き込むことはできない:
+    ///
+    /// ```compile_fail
+    /// use ff11sim::prelude::*;
+    ///
+    /// let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+    /// profile.job_levels[Job::War].level = 100;
+    /// ```
+    pub fn job_level(&self, job: Job) -> JobLevel {
+        self.job_levels[job]
+    }
+
+    /// 全ジョブのレベル情報
+    pub fn job_levels(&self) -> &EnumMap<Job, JobLevel> {
+        &self.job_levels
+    }
+
+    /// ジョブのレベル・マスターレベルを設定する。
+    /// 範囲外 (level: 0〜99, master_lv: 0〜50) の場合は何も変更せずエラーを返す。
+    pub fn try_set_job_level(
+        &mut self,
+        job: Job,
+        level: i32,
+        master_lv: i32,
+    ) -> Result<(), String> {
+        if !(0..=99).contains(&level) {
+            return Err(format!("level must be between 0 and 99: {}", level));
+        }
+        if !(0..=50).contains(&master_lv) {
+            return Err(format!("master_lv must be between 0 and 50: {}", master_lv));
+        }
         self.job_levels[job] = JobLevel { level, master_lv };
+        Ok(())
+    }
+
+    /// `try_set_job_level` の panic 版
+    pub fn set_job_level(&mut self, job: Job, level: i32, master_lv: i32) {
+        if let Err(e) = self.try_set_job_level(job, level, master_lv) {
+            panic!("{}", e);
+        }
+    }
+
+    /// 旧スキーマで読み込んだプロファイルを現行スキーマへ移行する。
+    /// 欠落フィールドはデシリアライズ時に既定値で補完済みのため、ここではバージョンを更新する。
+    /// 現行より新しいバージョンはエラー。
+    pub fn migrate(&mut self) -> Result<(), String> {
+        if self.schema_version > CURRENT_SCHEMA_VERSION {
+            return Err(format!(
+                "Unsupported schema_version {} (current: {})",
+                self.schema_version, CURRENT_SCHEMA_VERSION
+            ));
+        }
+        // v0 → v1: フィールド追加のみ (serde(default) で補完される)
+        // v1 → v2: merit_points 欠落時は MeritPoints::default() (serde(default) で補完される)
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        Ok(())
+    }
+
+    /// 全ジョブを同一のレベル・マスターレベルに設定する。
+    /// 範囲外 (level: 0〜99, master_lv: 0〜50) の場合は何も変更せずエラーを返す。
+    pub fn set_all_jobs(&mut self, level: i32, master_lv: i32) -> Result<(), String> {
+        if !(0..=99).contains(&level) {
+            return Err(format!("level must be between 0 and 99: {}", level));
+        }
+        if !(0..=50).contains(&master_lv) {
+            return Err(format!("master_lv must be between 0 and 50: {}", master_lv));
+        }
+        for (_, job_level) in self.job_levels.iter_mut() {
+            *job_level = JobLevel { level, master_lv };
+        }
+        Ok(())
+    }
+
+    /// 別ソースのプロファイルからジョブレベルを取り込む。
+    /// 名前・種族が異なるプロファイルは別キャラクターとみなし、何も変更せずエラーを返す。
+    pub fn merge(
+        &mut self,
+        other: &CharacterProfile,
+        strategy: MergeStrategy,
+    ) -> Result<(), String> {
+        if self.name != other.name {
+            return Err(format!(
+                "Cannot merge profiles with different names: '{}' and '{}'",
+                self.name, other.name
+            ));
+        }
+        if self.race != other.race {
+            return Err(format!(
+                "Cannot merge profiles with different races: {:?} and {:?}",
+                self.race, other.race
+            ));
+        }
+
+        for (job, theirs) in &other.job_levels {
+            if theirs.level == 0 {
+                continue;
+            }
+            let ours = &mut self.job_levels[job];
+            *ours = match strategy {
+                MergeStrategy::KeepHigher => JobLevel {
+                    level: ours.level.max(theirs.level),
+                    master_lv: ours.master_lv.max(theirs.master_lv),
+                },
+                MergeStrategy::Overwrite => *theirs,
+                MergeStrategy::KeepExisting if ours.level > 0 => *ours,
+                MergeStrategy::KeepExisting => *theirs,
+            };
+        }
+        Ok(())
+    }
+
+    /// プロファイルの入力値を検証し、不正な項目ごとのエラーメッセージを返す (空なら妥当)。
+    /// 名前の空文字、ジョブレベル (0〜99)・マスターレベル (0〜50)、メリットの上限段数を確認する。
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if normalize_name(&self.name).is_empty() {
+            errors.push("name must not be empty".to_string());
+        }
+        for (job, jl) in &self.job_levels {
+            if !(0..=99).contains(&jl.level) {
+                errors.push(format!(
+                    "{:?} level must be between 0 and 99: {}",
+                    job, jl.level
+                ));
+            }
+            if !(0..=50).contains(&jl.master_lv) {
+                errors.push(format!(
+                    "{:?} master_lv must be between 0 and 50: {}",
+                    job, jl.master_lv
+                ));
+            }
+        }
+        for &kind in StatusKind::VARIANTS {
+            let rank = self.merit_points.get(kind);
+            let spec = status_merit(kind);
+            if !spec.contains(rank) {
+                errors.push(format!(
+                    "{:?} merit point rank must be between 0 and {}: {}",
+                    kind, spec.max_rank, rank
+                ));
+            }
+        }
+        for (label, ranks, spec) in [
+            (
+                "combat",
+                &self.merit_points.combat_skill_merits,
+                COMBAT_SKILL_MERIT,
+            ),
+            (
+                "magic",
+                &self.merit_points.magic_skill_merits,
+                MAGIC_SKILL_MERIT,
+            ),
+        ] {
+            for (skill, &rank) in ranks {
+                if !spec.contains(rank) {
+                    errors.push(format!(
+                        "{} {} skill merit rank must be between 0 and {}: {}",
+                        skill, label, spec.max_rank, rank
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
+    /// このプロファイルのメモリ使用量の概算 (バイト)。本体に名前とスキルメリットのキー文字列分を足したもの
+    pub fn estimated_memory(&self) -> usize {
+        let skill_merits: usize = self
+            .merit_points
+            .combat_skill_merits
+            .keys()
+            .chain(self.merit_points.magic_skill_merits.keys())
+            .map(|skill| size_of::<(String, i32)>() + skill.len())
+            .sum();
+        size_of::<Self>() + self.name.len() + skill_merits
+    }
+
+    /// 全ジョブをサポートなし・装備なしでメインにしたときのステータス (マスターレベル・メリット込み)。
+    /// 未育成 (レベル 0) のジョブは参考値として 0 を返す。育成済みのジョブで Chara を生成できない
+    /// (メリットポイントが範囲外など、プロファイルのデータが不正な) 場合はエラー。
+    pub fn status_by_job(&self, kind: StatusKind) -> Result<EnumMap<Job, i32>, String> {
+        let mut stats = EnumMap::default();
+        for (job, jl) in &self.job_levels {
+            if jl.level > 0 {
+                stats[job] = self.to_chara(job, None)?.status(kind);
+            }
+        }
+        Ok(stats)
+    }
+
+    /// 育成状況のサマリ
+    pub fn summary(&self) -> ProfileSummary {
+        let levels = self.job_levels.values();
+        ProfileSummary {
+            leveled_jobs: levels.clone().filter(|jl| jl.level > 0).count(),
+            max_level_jobs: levels.clone().filter(|jl| jl.level == 99).count(),
+            total_master_lv: levels.map(|jl| jl.master_lv).sum(),
+        }
+    }
+
+    /// 最も育っているジョブ (レベルが最も高く、同レベルならマスターレベルが高いもの) を返す。
+    /// 完全に同値の場合は Job 定義順で先のジョブ。習得済みのジョブがなければ None。
+    pub fn highest_job(&self) -> Option<(Job, JobLevel)> {
+        self.job_levels
+            .iter()
+            .filter(|(_, jl)| jl.level > 0)
+            .rev()
+            .max_by_key(|(_, jl)| (jl.level, jl.master_lv))
+            .map(|(job, jl)| (job, *jl))
+    }
+
+    /// 全ジョブのレベル合計に応じた称号風のランク (`RANK_THRESHOLDS` を参照)
+    pub fn rank(&self) -> &'static str {
+        let total: i32 = self.job_levels.values().map(|jl| jl.level).sum();
+        RANK_THRESHOLDS
+            .iter()
+            .find(|&&(min, _)| total >= min)
+            .map(|&(_, name)| name)
+            .unwrap_or(RANK_THRESHOLDS[RANK_THRESHOLDS.len() - 1].1)
+    }
+
+    /// 過去時点のプロファイルからレベルが変化したジョブを (ジョブ, 過去Lv, 現Lv) で返す (Job 定義順)
+    pub fn diff_levels(&self, past: &CharacterProfile) -> Vec<(Job, i32, i32)> {
+        self.job_levels
+            .iter()
+            .filter(|(job, jl)| jl.level != past.job_levels[*job].level)
+            .map(|(job, jl)| (job, past.job_levels[job].level, jl.level))
+            .collect()
+    }
+
+    /// 育成目標 (ジョブ, 目標Lv) ごとの達成率 (現在Lv / 目標Lv、0.0〜1.0 にクランプ)。
+    /// 目標Lv が 0 以下の目標は達成済み (1.0) とみなす。
+    pub fn progress_to(&self, targets: &[(Job, i32)]) -> Vec<(Job, f32)> {
+        targets
+            .iter()
+            .map(|&(job, target)| {
+                let rate = if target <= 0 {
+                    1.0
+                } else {
+                    (self.job_levels[job].level as f32 / target as f32).clamp(0.0, 1.0)
+                };
+                (job, rate)
+            })
+            .collect()
+    }
+
+    /// 別キャラクターとの育成差を (ジョブ, 自分のLv, 相手のLv) で返す (Job 定義順)。
+    /// レベルが同じジョブ (両方未習得を含む) は含めない。
+    pub fn diff_jobs(&self, other: &CharacterProfile) -> Vec<(Job, i32, i32)> {
+        self.job_levels
+            .iter()
+            .map(|(job, jl)| (job, jl.level, other.job_levels[job].level))
+            .filter(|&(_, ours, theirs)| ours != theirs)
+            .collect()
     }
 
     /// 指定したメインジョブ・サポートジョブ構成で Chara を生成する。
     /// サポートジョブの有効レベルは min(実レベル, メインLv/2 + マスターLv/5) で自動計算。
-    pub fn to_chara(&self, main_job: Job, support_job: Option<Job>) -> Result<Chara, String> {
-        let main_jl = &self.job_levels[main_job];
-        if main_jl.level == 0 {
-            return Err(format!("{:?} is not leveled", main_job));
+    pub fn to_chara(&self, main_job: Job, support_job: Option<Job>) -> Result<Chara, ProfileError> {
+        self.to_chara_at(main_job, support_job, None)
+    }
+
+    /// 同じメインジョブで複数のサポートジョブ案の Chara をまとめて生成する (`subs` の順)。
+    /// 未習得のサポートなど生成できなかった構成はエラーのまま返す。
+    pub fn compare_supports(
+        &self,
+        main: Job,
+        subs: &[Job],
+    ) -> Vec<(Job, Result<Chara, ProfileError>)> {
+        subs.iter()
+            .map(|&sub| (sub, self.to_chara(main, Some(sub))))
+            .collect()
+    }
+
+    /// 2 ジョブの組み合わせのうち、A をメイン・B をサポートとした構成を生成する
+    /// (メイン/サポートを入れ替えた構成は `to_chara_reversed`)。
+    /// どちらかのジョブが未習得の場合はエラー。
+    pub fn to_chara_swapped(&self, job_a: Job, job_b: Job) -> Result<Chara, ProfileError> {
+        self.to_chara(job_a, Some(job_b))
+    }
+
+    /// `to_chara_swapped` の逆版: B をメイン・A をサポートとした構成を生成する。
+    /// どちらかのジョブが未習得の場合はエラー。
+    pub fn to_chara_reversed(&self, job_a: Job, job_b: Job) -> Result<Chara, ProfileError> {
+        self.to_chara_swapped(job_b, job_a)
+    }
+
+    /// `to_chara` と同様だが、`main_lv_override` を指定するとメインジョブをそのレベルとして扱う
+    /// (プロファイル自体は変更しない)。サポートジョブのキャップも上書き後のレベルで再計算する。
+    /// 上書き値が 1 未満、または実レベルを超える場合はエラー。
+    pub fn to_chara_at(
+        &self,
+        main_job: Job,
+        support_job: Option<Job>,
+        main_lv_override: Option<i32>,
+    ) -> Result<Chara, ProfileError> {
+        let actual = &self.job_levels[main_job];
+        if actual.level == 0 {
+            return Err(ProfileError::NotLeveled {
+                job: main_job,
+                support: false,
+            });
         }
+        let main_jl = match main_lv_override {
+            Some(lv) if !(1..=actual.level).contains(&lv) => {
+                return Err(ProfileError::MainLvOverrideOutOfRange {
+                    job: main_job,
+                    max: actual.level,
+                    lv,
+                });
+            }
+            Some(lv) => &JobLevel {
+                level: lv,
+                master_lv: actual.master_lv,
+            },
+            None => actual,
+        };
 
         let mut builder = Chara::builder()
             .race(self.race)
@@ -71,50 +479,159 @@ impl CharacterProfile {
         if let Some(sub) = support_job {
             let sub_jl = &self.job_levels[sub];
             if sub_jl.level == 0 {
-                return Err(format!("Support job {:?} is not leveled", sub));
+                return Err(ProfileError::NotLeveled {
+                    job: sub,
+                    support: true,
+                });
             }
-            let cap = main_jl.level / 2 + main_jl.master_lv / 5;
-            let effective_lv = std::cmp::min(sub_jl.level, cap);
+            let effective_lv =
+                effective_support_level(sub_jl.level, main_jl.level, main_jl.master_lv);
             if effective_lv > 0 {
                 builder = builder.support_job(sub, effective_lv);
             }
         }
 
-        builder.build().map_err(|e| e.to_string())
+        Ok(builder.build()?)
+    }
+}
+
+impl CharacterProfile {
+    /// メインジョブを固定し、指定ステータスが高い順にサポートジョブ候補を並べる。
+    /// 候補はサポートなし (None) とレベル 1 以上の全ジョブ (メインジョブを除く)。
+    /// 同値の場合はサポートなし → Job 定義順。メインジョブが未習得の場合はエラー。
+    pub fn rank_supports(
+        &self,
+        main_job: Job,
+        kind: StatusKind,
+    ) -> Result<Vec<(Option<Job>, i32)>, String> {
+        let candidates = std::iter::once(None).chain(
+            self.job_levels
+                .iter()
+                .filter(|&(job, jl)| job != main_job && jl.level > 0)
+                .map(|(job, _)| Some(job)),
+        );
+        let mut ranked = candidates
+            .map(|sub| Ok((sub, self.to_chara(main_job, sub)?.status(kind))))
+            .collect::<Result<Vec<_>, String>>()?;
+        ranked.sort_by_key(|&(_, value)| std::cmp::Reverse(value));
+        Ok(ranked)
+    }
+}
+
+/// 任意バージョンのプロファイル JSON を読み込み、現行スキーマへ移行する。
+pub fn migrate_profile(json: &str) -> Result<CharacterProfile, String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    migrate_profile_value(value)
+}
+
+fn migrate_profile_value(value: serde_json::Value) -> Result<CharacterProfile, String> {
+    let version = value
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(format!(
+            "Unsupported schema_version {} (current: {})",
+            version, CURRENT_SCHEMA_VERSION
+        ));
     }
+
+    let mut profile: CharacterProfile = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    profile.migrate()?;
+    Ok(profile)
+}
+
+/// `CharaRegistry::save_to_json` の出力形式バージョン
+pub const REGISTRY_EXPORT_VERSION: u32 = 1;
+
+/// `CharaRegistry::save_to_json` の出力 (メタ情報付き)
+#[derive(Serialize)]
+struct RegistryExport<'a> {
+    version: u32,
+    exported_at: &'a str,
+    characters: &'a [CharacterProfile],
 }
 
 /// キャラクター登録管理
+#[derive(Debug, Default)]
 pub struct CharaRegistry {
     characters: Vec<CharacterProfile>,
+    /// 登録数の上限 (None は無制限)
+    max: Option<usize>,
 }
 
 impl CharaRegistry {
+    /// 登録数無制限のレジストリ
     pub fn new() -> Self {
         Self {
             characters: Vec::new(),
+            max: None,
         }
     }
 
-    pub fn register(&mut self, profile: CharacterProfile) -> Result<(), String> {
-        if self.characters.iter().any(|c| c.name == profile.name) {
+    /// 登録数の上限付きレジストリ (上限を超える `register` はエラー)。
+    /// 上限は記録するだけで、その分のメモリを事前に確保はしない
+    pub fn with_capacity(max: usize) -> Self {
+        Self {
+            characters: Vec::new(),
+            max: Some(max),
+        }
+    }
+
+    /// 登録数の上限 (None は無制限)
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    /// 登録済みキャラクターのメモリ使用量の概算 (バイト)。
+    /// 各プロファイル本体に名前・スキルメリットのヒープ分を足したもので、アロケータのオーバーヘッドは含まない
+    pub fn estimated_memory(&self) -> usize {
+        self.characters
+            .iter()
+            .map(CharacterProfile::estimated_memory)
+            .sum()
+    }
+
+    /// `count` 人登録したときのメモリ使用量の下限見積り (バイト)。`with_capacity` の上限を決める目安。
+    /// 名前・スキルメリットのヒープ分は含まない
+    pub fn estimated_memory_for(count: usize) -> usize {
+        count.saturating_mul(size_of::<CharacterProfile>())
+    }
+
+    /// 名前を正規化して登録する。正規化後の名前が空、または登録済みの名前と重複する場合はエラー
+    pub fn register(&mut self, mut profile: CharacterProfile) -> Result<(), String> {
+        profile.name = normalize_name(&profile.name).to_string();
+        if profile.name.is_empty() {
+            return Err("name must not be empty".to_string());
+        }
+        if self.get(&profile.name).is_some() {
             return Err(format!("Character '{}' already exists", profile.name));
         }
+        if self.max.is_some_and(|max| self.characters.len() >= max) {
+            return Err("registry is full".to_string());
+        }
         self.characters.push(profile);
         Ok(())
     }
 
     pub fn get(&self, name: &str) -> Option<&CharacterProfile> {
-        self.characters.iter().find(|c| c.name == name)
+        let name = normalize_name(name);
+        self.characters
+            .iter()
+            .find(|c| normalize_name(&c.name) == name)
     }
 
     pub fn get_mut(&mut self, name: &str) -> Option<&mut CharacterProfile> {
-        self.characters.iter_mut().find(|c| c.name == name)
+        let name = normalize_name(name);
+        self.characters
+            .iter_mut()
+            .find(|c| normalize_name(&c.name) == name)
     }
 
     pub fn remove(&mut self, name: &str) -> bool {
+        let name = normalize_name(name);
         let len = self.characters.len();
-        self.characters.retain(|c| c.name != name);
+        self.characters.retain(|c| normalize_name(&c.name) != name);
         self.characters.len() != len
     }
 
@@ -122,6 +639,65 @@ impl CharaRegistry {
         self.characters.iter().map(|c| c.name.as_str()).collect()
     }
 
+    /// 登録済みのキャラクターを登録順に反復する
+    pub fn iter(&self) -> impl Iterator<Item = &CharacterProfile> {
+        self.characters.iter()
+    }
+
+    /// 条件に一致するキャラクターを登録順で返す
+    pub fn find(&self, pred: impl Fn(&CharacterProfile) -> bool) -> Vec<&CharacterProfile> {
+        self.characters.iter().filter(|c| pred(c)).collect()
+    }
+
+    /// 指定種族のキャラクターを返す
+    pub fn by_race(&self, race: Race) -> Vec<&CharacterProfile> {
+        self.find(|c| c.race == race)
+    }
+
+    /// 指定ジョブのレベルが 1 以上のキャラクターを返す
+    pub fn with_job_leveled(&self, job: Job) -> Vec<&CharacterProfile> {
+        self.find(|c| c.job_levels[job].level > 0)
+    }
+
+    /// 全キャラクターを `{ version, exported_at, characters }` 形式の JSON で出力する
+    pub fn save_to_json(&self, exported_at: &str) -> Result<String, String> {
+        serde_json::to_string(&RegistryExport {
+            version: REGISTRY_EXPORT_VERSION,
+            exported_at,
+            characters: &self.characters,
+        })
+        .map_err(|e| e.to_string())
+    }
+
+    /// `save_to_json` の出力、またはメタ情報なしのプロファイル配列 (旧形式) から読み込む。
+    /// 各プロファイルは `migrate_profile` と同様に現行スキーマへ移行する。
+    pub fn load_from_json(json: &str) -> Result<CharaRegistry, String> {
+        let value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let characters = match value {
+            serde_json::Value::Array(characters) => characters,
+            serde_json::Value::Object(mut meta) => {
+                let version = meta.get("version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+                if version > REGISTRY_EXPORT_VERSION {
+                    return Err(format!(
+                        "Unsupported export version {} (current: {})",
+                        version, REGISTRY_EXPORT_VERSION
+                    ));
+                }
+                match meta.remove("characters") {
+                    Some(serde_json::Value::Array(characters)) => characters,
+                    _ => return Err("characters must be an array".to_string()),
+                }
+            }
+            _ => return Err("registry JSON must be an object or an array".to_string()),
+        };
+
+        let mut registry = CharaRegistry::new();
+        for character in characters {
+            registry.register(migrate_profile_value(character)?)?;
+        }
+        Ok(registry)
+    }
+
     /// 登録済みキャラクターを指定して Chara を生成する
     pub fn to_chara(
         &self,
@@ -132,7 +708,7 @@ impl CharaRegistry {
         let profile = self
             .get(name)
             .ok_or_else(|| format!("Character '{}' not found", name))?;
-        profile.to_chara(main_job, support_job)
+        Ok(profile.to_chara(main_job, support_job)?)
     }
 }
 
@@ -153,6 +729,94 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_migrate_profile_v0() {
+        // schema_version を持たない v0 形式 (job_points / skills も省略)
+        let json = r#"{
+            "name": "OldChar",
+            "race": "Elv",
+            "job_levels": {
+                "War": {"level": 0, "master_lv": 0}, "Mnk": {"level": 0, "master_lv": 0},
+                "Whm": {"level": 0, "master_lv": 0}, "Blm": {"level": 0, "master_lv": 0},
+                "Rdm": {"level": 0, "master_lv": 0}, "Thf": {"level": 0, "master_lv": 0},
+                "Pld": {"level": 99, "master_lv": 20}, "Drk": {"level": 0, "master_lv": 0},
+                "Bst": {"level": 0, "master_lv": 0}, "Brd": {"level": 0, "master_lv": 0},
+                "Rng": {"level": 0, "master_lv": 0}, "Sam": {"level": 0, "master_lv": 0},
+                "Nin": {"level": 0, "master_lv": 0}, "Drg": {"level": 0, "master_lv": 0},
+                "Smn": {"level": 0, "master_lv": 0}, "Blu": {"level": 0, "master_lv": 0},
+                "Cor": {"level": 0, "master_lv": 0}, "Pup": {"level": 0, "master_lv": 0},
+                "Dnc": {"level": 0, "master_lv": 0}, "Sch": {"level": 0, "master_lv": 0},
+                "Geo": {"level": 0, "master_lv": 0}, "Run": {"level": 0, "master_lv": 0}
+            },
+            "merit_points": {
+                "hp": 15, "mp": 0, "str_": 0, "dex": 0, "vit": 0,
+                "agi": 0, "int": 0, "mnd": 0, "chr": 0
+            }
+        }"#;
+
+        let profile = migrate_profile(json).unwrap();
+        assert_eq!(profile.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(profile.name, "OldChar");
+        assert_eq!(profile.race, Race::Elv);
+        assert_eq!(profile.job_levels[Job::Pld].level, 99);
+        assert_eq!(profile.job_levels[Job::Pld].master_lv, 20);
+        assert_eq!(profile.merit_points.hp, 15);
+        assert!(profile.to_chara(Job::Pld, None).is_ok());
+    }
+
+    #[test]
+    fn test_migrate_profile_v1_without_merit_points() {
+        // v1 では必須だった merit_points を省略した JSON も既定値で読める
+        let mut value =
+            serde_json::to_value(CharacterProfile::new("OldChar".to_string(), Race::Tar)).unwrap();
+        value["schema_version"] = serde_json::json!(1);
+        value.as_object_mut().unwrap().remove("merit_points");
+
+        let profile = migrate_profile(&value.to_string()).unwrap();
+        assert_eq!(profile.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(profile.merit_points.hp, 0);
+        assert_eq!(profile.merit_points.str_, 0);
+    }
+
+    #[test]
+    fn test_migrate_explicit() {
+        // デシリアライズ直後は旧バージョンのまま、migrate() で現行に上がる
+        let mut value =
+            serde_json::to_value(CharacterProfile::new("OldChar".to_string(), Race::Hum)).unwrap();
+        value["schema_version"] = serde_json::json!(1);
+        let mut profile: CharacterProfile = serde_json::from_value(value).unwrap();
+        assert_eq!(profile.schema_version, 1);
+        profile.migrate().unwrap();
+        assert_eq!(profile.schema_version, CURRENT_SCHEMA_VERSION);
+
+        profile.schema_version = CURRENT_SCHEMA_VERSION + 1;
+        assert!(profile
+            .migrate()
+            .unwrap_err()
+            .contains("Unsupported schema_version"));
+    }
+
+    #[test]
+    fn test_migrate_profile_roundtrip_current() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        let json = serde_json::to_string(&profile).unwrap();
+
+        let migrated = migrate_profile(&json).unwrap();
+        assert_eq!(migrated.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(migrated.job_levels[Job::War].level, 99);
+    }
+
+    #[test]
+    fn test_migrate_profile_future_version_error() {
+        let mut value =
+            serde_json::to_value(CharacterProfile::new("TestChar".to_string(), Race::Hum)).unwrap();
+        value["schema_version"] = serde_json::json!(CURRENT_SCHEMA_VERSION + 1);
+
+        let result = migrate_profile(&value.to_string());
+        assert!(result.unwrap_err().contains("Unsupported schema_version"));
+    }
+
     #[test]
     fn test_set_job_level() {
         let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
@@ -163,6 +827,355 @@ mod tests {
         assert_eq!(profile.job_levels[Job::Blm].level, 0);
     }
 
+    fn merge_sources() -> (CharacterProfile, CharacterProfile) {
+        let mut manual = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        manual.set_job_level(Job::War, 99, 10);
+        manual.set_job_level(Job::Blm, 75, 0);
+        let mut imported = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        imported.set_job_level(Job::War, 90, 20);
+        imported.set_job_level(Job::Blm, 99, 5);
+        imported.set_job_level(Job::Whm, 50, 0);
+        (manual, imported)
+    }
+
+    #[test]
+    fn test_try_set_job_level() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.try_set_job_level(Job::War, 99, 50).unwrap();
+        assert_eq!(
+            profile.job_level(Job::War),
+            JobLevel {
+                level: 99,
+                master_lv: 50
+            }
+        );
+
+        // 範囲外はエラーで、既存の設定は変わらない
+        assert_eq!(
+            profile.try_set_job_level(Job::War, 100, 0).unwrap_err(),
+            "level must be between 0 and 99: 100"
+        );
+        assert_eq!(
+            profile.try_set_job_level(Job::War, 99, 51).unwrap_err(),
+            "master_lv must be between 0 and 50: 51"
+        );
+        assert!(profile.try_set_job_level(Job::War, -1, 0).is_err());
+        assert_eq!(
+            profile.job_level(Job::War),
+            JobLevel {
+                level: 99,
+                master_lv: 50
+            }
+        );
+        assert_eq!(profile.job_levels()[Job::Blm].level, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "level must be between 0 and 99")]
+    fn test_set_job_level_panics_out_of_range() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 100, 0);
+    }
+
+    #[test]
+    fn test_status_by_job_unleveled_is_zero() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 0);
+        let hp = profile.status_by_job(StatusKind::Hp).unwrap();
+        // Hum/War99: race 485 + job 675 + trait 180
+        assert_eq!(hp[Job::War], 1340);
+        assert!(hp
+            .iter()
+            .filter(|&(job, _)| job != Job::War)
+            .all(|(_, &v)| v == 0));
+        assert!(CharacterProfile::new("Empty".to_string(), Race::Hum)
+            .status_by_job(StatusKind::Str)
+            .unwrap()
+            .values()
+            .all(|&v| v == 0));
+
+        // 不正なデータは 0 扱いにせずエラー
+        profile.merit_points.str_ = 16;
+        assert!(profile.status_by_job(StatusKind::Str).is_err());
+    }
+
+    #[test]
+    fn test_set_all_jobs() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_all_jobs(99, 50).unwrap();
+        assert!(
+            profile
+                .job_levels
+                .values()
+                .all(|jl| jl.level == 99 && jl.master_lv == 50)
+        );
+        assert_eq!(profile.summary().max_level_jobs, 22);
+
+        // 範囲外はエラーで、既存の設定は変わらない
+        assert!(profile.set_all_jobs(100, 0).is_err());
+        assert!(profile.set_all_jobs(-1, 0).is_err());
+        assert!(profile.set_all_jobs(99, 51).is_err());
+        assert!(profile.job_levels.values().all(|jl| jl.level == 99));
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        assert!(profile.validate().is_empty());
+
+        // 不正な項目ごとにエラーが返る
+        profile.name = " ".to_string();
+        profile.job_levels[Job::War].level = 100;
+        profile.job_levels[Job::Blm].master_lv = 51;
+        profile.merit_points.hp = 16;
+        profile
+            .merit_points
+            .combat_skill_merits
+            .insert("GreatAxe".to_string(), 9);
+        assert_eq!(
+            profile.validate(),
+            vec![
+                "name must not be empty",
+                "War level must be between 0 and 99: 100",
+                "Blm master_lv must be between 0 and 50: 51",
+                "Hp merit point rank must be between 0 and 15: 16",
+                "GreatAxe combat skill merit rank must be between 0 and 8: 9",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_keep_higher() {
+        let (mut profile, imported) = merge_sources();
+        profile.merge(&imported, MergeStrategy::KeepHigher).unwrap();
+        assert_eq!(profile.job_levels[Job::War].level, 99);
+        assert_eq!(profile.job_levels[Job::War].master_lv, 20);
+        assert_eq!(profile.job_levels[Job::Blm].level, 99);
+        assert_eq!(profile.job_levels[Job::Blm].master_lv, 5);
+        assert_eq!(profile.job_levels[Job::Whm].level, 50);
+    }
+
+    #[test]
+    fn test_merge_overwrite() {
+        let (mut profile, imported) = merge_sources();
+        profile.merge(&imported, MergeStrategy::Overwrite).unwrap();
+        assert_eq!(profile.job_levels[Job::War].level, 90);
+        assert_eq!(profile.job_levels[Job::War].master_lv, 20);
+        assert_eq!(profile.job_levels[Job::Blm].level, 99);
+        assert_eq!(profile.job_levels[Job::Whm].level, 50);
+    }
+
+    #[test]
+    fn test_merge_keep_existing() {
+        let (mut profile, imported) = merge_sources();
+        profile
+            .merge(&imported, MergeStrategy::KeepExisting)
+            .unwrap();
+        assert_eq!(profile.job_levels[Job::War].level, 99);
+        assert_eq!(profile.job_levels[Job::War].master_lv, 10);
+        assert_eq!(profile.job_levels[Job::Blm].level, 75);
+        // 未習得だったジョブのみ取り込まれる
+        assert_eq!(profile.job_levels[Job::Whm].level, 50);
+    }
+
+    #[test]
+    fn test_merge_unleveled_source_job_is_ignored() {
+        let (mut profile, _) = merge_sources();
+        let empty = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.merge(&empty, MergeStrategy::Overwrite).unwrap();
+        assert_eq!(profile.job_levels[Job::War].level, 99);
+        assert_eq!(profile.job_levels[Job::Blm].level, 75);
+    }
+
+    #[test]
+    fn test_merge_mismatch_error() {
+        let (mut profile, _) = merge_sources();
+        let mut other = CharacterProfile::new("Other".to_string(), Race::Hum);
+        other.set_job_level(Job::War, 1, 0);
+        assert!(
+            profile
+                .merge(&other, MergeStrategy::Overwrite)
+                .unwrap_err()
+                .contains("different names")
+        );
+
+        let mut other = CharacterProfile::new("TestChar".to_string(), Race::Elv);
+        other.set_job_level(Job::War, 1, 0);
+        assert!(
+            profile
+                .merge(&other, MergeStrategy::Overwrite)
+                .unwrap_err()
+                .contains("different races")
+        );
+        // エラー時は変更されない
+        assert_eq!(profile.job_levels[Job::War].level, 99);
+    }
+
+    #[test]
+    fn test_summary_empty() {
+        let profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        assert_eq!(profile.summary(), ProfileSummary::default());
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        profile.set_job_level(Job::Blm, 99, 12);
+        profile.set_job_level(Job::Nin, 37, 0);
+        assert_eq!(
+            profile.summary(),
+            ProfileSummary {
+                leveled_jobs: 3,
+                max_level_jobs: 2,
+                total_master_lv: 62,
+            }
+        );
+    }
+
+    #[test]
+    fn test_highest_job() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        assert!(profile.highest_job().is_none());
+
+        profile.set_job_level(Job::Nin, 37, 0);
+        profile.set_job_level(Job::War, 99, 10);
+        profile.set_job_level(Job::Blm, 99, 12);
+        let (job, jl) = profile.highest_job().unwrap();
+        assert_eq!(job, Job::Blm);
+        assert_eq!((jl.level, jl.master_lv), (99, 12));
+
+        // 完全に同値なら Job 定義順で先のジョブ
+        profile.set_job_level(Job::War, 99, 12);
+        assert_eq!(profile.highest_job().unwrap().0, Job::War);
+    }
+
+    #[test]
+    fn test_rank() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        assert_eq!(profile.rank(), "新米");
+
+        profile.set_job_level(Job::War, 99, 0);
+        profile.set_job_level(Job::Blm, 99, 0);
+        profile.set_job_level(Job::Whm, 99, 0);
+        assert_eq!(profile.rank(), "新米");
+        profile.set_job_level(Job::Nin, 3, 0);
+        assert_eq!(profile.rank(), "一人前");
+
+        profile.set_all_jobs(98, 0).unwrap();
+        assert_eq!(profile.rank(), "ベテラン");
+
+        profile.set_all_jobs(99, 0).unwrap();
+        assert_eq!(profile.rank(), "マスター");
+    }
+
+    #[test]
+    fn test_diff_levels() {
+        let mut past = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        past.set_job_level(Job::War, 75, 0);
+        past.set_job_level(Job::Blm, 99, 0);
+        let mut now = past.clone();
+        now.set_job_level(Job::War, 99, 0);
+        now.set_job_level(Job::Nin, 37, 0);
+        // マスターレベルのみの変化はレベル差分に含めない
+        now.set_job_level(Job::Blm, 99, 10);
+
+        assert_eq!(
+            now.diff_levels(&past),
+            vec![(Job::War, 75, 99), (Job::Nin, 0, 37)]
+        );
+        assert!(now.diff_levels(&now).is_empty());
+    }
+
+    #[test]
+    fn test_progress_to() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 0);
+        profile.set_job_level(Job::Blm, 45, 0);
+        profile.set_job_level(Job::Nin, 30, 0);
+
+        let progress = profile.progress_to(&[
+            (Job::Blm, 90),
+            (Job::Nin, 60),
+            (Job::War, 75),
+            (Job::Whm, 50),
+            (Job::Drg, 0),
+        ]);
+        assert_eq!(
+            progress,
+            vec![
+                (Job::Blm, 0.5),
+                (Job::Nin, 0.5),
+                // 目標超えは 1.0 にクランプ
+                (Job::War, 1.0),
+                (Job::Whm, 0.0),
+                (Job::Drg, 1.0),
+            ]
+        );
+        assert!(profile.progress_to(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_diff_jobs() {
+        let mut alice = CharacterProfile::new("Alice".to_string(), Race::Hum);
+        alice.set_job_level(Job::War, 99, 50);
+        alice.set_job_level(Job::Blm, 75, 0);
+        alice.set_job_level(Job::Drg, 50, 0);
+        let mut bob = CharacterProfile::new("Bob".to_string(), Race::Elv);
+        bob.set_job_level(Job::War, 99, 0);
+        bob.set_job_level(Job::Blm, 99, 0);
+        bob.set_job_level(Job::Nin, 37, 0);
+
+        assert!(alice.diff_jobs(&alice).is_empty());
+        // War は同レベル (マスターレベルの差は含めない)、両方未習得のジョブも除外
+        assert_eq!(
+            alice.diff_jobs(&bob),
+            vec![(Job::Blm, 75, 99), (Job::Nin, 0, 37), (Job::Drg, 50, 0)]
+        );
+        assert_eq!(
+            bob.diff_jobs(&alice),
+            vec![(Job::Blm, 99, 75), (Job::Nin, 37, 0), (Job::Drg, 0, 50)]
+        );
+    }
+
+    #[test]
+    fn test_rank_supports() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        profile.set_job_level(Job::Drg, 59, 0);
+        profile.set_job_level(Job::Blm, 59, 0);
+        profile.set_job_level(Job::Mnk, 59, 0);
+
+        let ranked = profile.rank_supports(Job::War, StatusKind::Str).unwrap();
+        let subs: Vec<Option<Job>> = ranked.iter().map(|&(sub, _)| sub).collect();
+        assert_eq!(subs.len(), 4);
+        assert_eq!(
+            ranked[ranked.len() - 1],
+            (
+                None,
+                profile
+                    .to_chara(Job::War, None)
+                    .unwrap()
+                    .status(StatusKind::Str)
+            )
+        );
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(subs.contains(&Some(Job::Drg)));
+        assert!(!subs.contains(&Some(Job::War)));
+        // War/Drg の STR は既存の期待値 146
+        assert!(ranked.contains(&(Some(Job::Drg), 146)));
+
+        // サポート候補がなければサポートなしのみ
+        let mut solo = CharacterProfile::new("Solo".to_string(), Race::Hum);
+        solo.set_job_level(Job::War, 99, 0);
+        assert_eq!(
+            solo.rank_supports(Job::War, StatusKind::Str).unwrap(),
+            vec![(None, 82)]
+        );
+        assert!(solo.rank_supports(Job::Blm, StatusKind::Str).is_err());
+    }
+
     #[test]
     fn test_to_chara_war_drg() {
         // Hum/War99/Drg/MLV50 — 既存テストと同じ結果になることを検証
@@ -172,8 +1185,8 @@ mod tests {
         profile.set_job_level(Job::Drg, 59, 0);
 
         let chara = profile.to_chara(Job::War, Some(Job::Drg)).unwrap();
-        assert_eq!(chara.status(StatusKind::Hp), 1945);
-        assert_eq!(chara.status(StatusKind::Str), 147);
+        assert_eq!(chara.status(StatusKind::Hp), 1915);
+        assert_eq!(chara.status(StatusKind::Str), 146);
         assert_eq!(chara.status(StatusKind::Mp), 0);
     }
 
@@ -185,13 +1198,13 @@ mod tests {
         profile.set_job_level(Job::Sam, 59, 0);
 
         let chara = profile.to_chara(Job::Cor, Some(Job::Sam)).unwrap();
-        assert_eq!(chara.status(StatusKind::Str), 138);
-        assert_eq!(chara.status(StatusKind::Dex), 141);
-        assert_eq!(chara.status(StatusKind::Vit), 143);
-        assert_eq!(chara.status(StatusKind::Agi), 138);
-        assert_eq!(chara.status(StatusKind::Int), 135);
-        assert_eq!(chara.status(StatusKind::Mnd), 132);
-        assert_eq!(chara.status(StatusKind::Chr), 127);
+        assert_eq!(chara.status(StatusKind::Str), 136);
+        assert_eq!(chara.status(StatusKind::Dex), 139);
+        assert_eq!(chara.status(StatusKind::Vit), 141);
+        assert_eq!(chara.status(StatusKind::Agi), 137);
+        assert_eq!(chara.status(StatusKind::Int), 133);
+        assert_eq!(chara.status(StatusKind::Mnd), 130);
+        assert_eq!(chara.status(StatusKind::Chr), 125);
     }
 
     #[test]
@@ -202,7 +1215,7 @@ mod tests {
         profile.set_job_level(Job::Rdm, 59, 0);
 
         let chara = profile.to_chara(Job::Blm, Some(Job::Rdm)).unwrap();
-        assert_eq!(chara.status(StatusKind::Mp), 1692);
+        assert_eq!(chara.status(StatusKind::Mp), 1662);
     }
 
     #[test]
@@ -241,12 +1254,60 @@ mod tests {
         assert_eq!(chara.support_lv, Some(30));
     }
 
+    #[test]
+    fn test_to_chara_at() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        profile.set_job_level(Job::Drg, 99, 0);
+
+        // 上書きなしは to_chara と一致
+        let base = profile.to_chara(Job::War, Some(Job::Drg)).unwrap();
+        let same = profile.to_chara_at(Job::War, Some(Job::Drg), None).unwrap();
+        assert_eq!(base.main_lv, same.main_lv);
+        assert_eq!(base.support_lv, same.support_lv);
+        assert_eq!(base.to_status(), same.to_status());
+
+        // Lv75 で上書き -> キャップ = 75/2 + 50/5 = 47
+        let at75 = profile
+            .to_chara_at(Job::War, Some(Job::Drg), Some(75))
+            .unwrap();
+        assert_eq!(at75.main_lv, 75);
+        assert_eq!(at75.support_lv, Some(47));
+        assert_eq!(at75.master_lv, 50);
+        // プロファイルは変更されない
+        assert_eq!(profile.job_levels[Job::War].level, 99);
+
+        // 実レベル超え・範囲外はエラー
+        profile.set_job_level(Job::War, 75, 0);
+        let err = profile.to_chara_at(Job::War, None, Some(76)).unwrap_err();
+        assert_eq!(
+            err,
+            ProfileError::MainLvOverrideOutOfRange {
+                job: Job::War,
+                max: 75,
+                lv: 76
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "main_lv_override must be between 1 and 75 (War): 76"
+        );
+        assert!(profile.to_chara_at(Job::War, None, Some(0)).is_err());
+        assert!(profile.to_chara_at(Job::War, None, Some(75)).is_ok());
+    }
+
     #[test]
     fn test_to_chara_unleveled_main_job_error() {
         let profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
-        let result = profile.to_chara(Job::War, None);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not leveled"));
+        let err = profile.to_chara(Job::War, None).unwrap_err();
+        assert_eq!(
+            err,
+            ProfileError::NotLeveled {
+                job: Job::War,
+                support: false
+            }
+        );
+        assert_eq!(err.to_string(), "War is not leveled");
     }
 
     #[test]
@@ -254,9 +1315,83 @@ mod tests {
         let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
         profile.set_job_level(Job::War, 99, 50);
 
-        let result = profile.to_chara(Job::War, Some(Job::Drg));
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not leveled"));
+        let err = profile.to_chara(Job::War, Some(Job::Drg)).unwrap_err();
+        assert_eq!(
+            err,
+            ProfileError::NotLeveled {
+                job: Job::Drg,
+                support: true
+            }
+        );
+        assert_eq!(err.to_string(), "Support job Drg is not leveled");
+    }
+
+    #[test]
+    fn test_compare_supports() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        profile.set_job_level(Job::Sam, 99, 0);
+        profile.set_job_level(Job::Drg, 40, 0);
+
+        let results = profile.compare_supports(Job::War, &[Job::Sam, Job::Nin, Job::Drg]);
+        let jobs: Vec<Job> = results.iter().map(|(job, _)| *job).collect();
+        assert_eq!(jobs, vec![Job::Sam, Job::Nin, Job::Drg]);
+
+        let sam = results[0].1.as_ref().unwrap();
+        assert_eq!(sam, &profile.to_chara(Job::War, Some(Job::Sam)).unwrap());
+        assert_eq!(
+            results[1].1.as_ref().unwrap_err().to_string(),
+            "Support job Nin is not leveled"
+        );
+        // Drg は実レベル 40 が上限
+        assert_eq!(results[2].1.as_ref().unwrap().support_lv, Some(40));
+
+        assert!(profile.compare_supports(Job::War, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_to_chara_swapped() {
+        let mut profile = CharacterProfile::new("TestChar".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 0);
+        profile.set_job_level(Job::Blm, 99, 0);
+
+        // A をメイン・B をサポート
+        let war_blm = profile.to_chara_swapped(Job::War, Job::Blm).unwrap();
+        assert_eq!(war_blm, profile.to_chara(Job::War, Some(Job::Blm)).unwrap());
+        assert_eq!(
+            (war_blm.main_job, war_blm.support_job),
+            (Job::War, Some(Job::Blm))
+        );
+        // 逆版は B をメイン・A をサポート
+        let blm_war = profile.to_chara_reversed(Job::War, Job::Blm).unwrap();
+        assert_eq!(blm_war, profile.to_chara(Job::Blm, Some(Job::War)).unwrap());
+        assert_eq!(
+            (blm_war.main_job, blm_war.support_job),
+            (Job::Blm, Some(Job::War))
+        );
+        // War メインの方が STR が高く、Blm メインの方が MP が高い
+        assert!(war_blm.status(StatusKind::Str) > blm_war.status(StatusKind::Str));
+        assert!(war_blm.status(StatusKind::Mp) < blm_war.status(StatusKind::Mp));
+
+        // どちらかが未習得ならエラー
+        let thf_main = ProfileError::NotLeveled {
+            job: Job::Thf,
+            support: false,
+        };
+        let thf_support = ProfileError::NotLeveled {
+            job: Job::Thf,
+            support: true,
+        };
+        assert_eq!(
+            profile.to_chara_swapped(Job::War, Job::Thf),
+            Err(thf_support)
+        );
+        assert_eq!(profile.to_chara_swapped(Job::Thf, Job::War), Err(thf_main));
+        assert_eq!(profile.to_chara_reversed(Job::War, Job::Thf), Err(thf_main));
+        assert_eq!(
+            profile.to_chara_reversed(Job::Thf, Job::War),
+            Err(thf_support)
+        );
     }
 
     #[test]
@@ -309,6 +1444,110 @@ mod tests {
         assert!(result.unwrap_err().contains("already exists"));
     }
 
+    #[test]
+    fn test_try_new_trims_name() {
+        let profile = CharacterProfile::try_new("  Alice ".to_string(), Race::Hum).unwrap();
+        assert_eq!(profile.name, "Alice");
+        assert_eq!(
+            CharacterProfile::new(" Bob".to_string(), Race::Elv).name,
+            "Bob"
+        );
+        assert_eq!(
+            CharacterProfile::try_new("   ".to_string(), Race::Hum).unwrap_err(),
+            "name must not be empty"
+        );
+        assert!(CharacterProfile::try_new(String::new(), Race::Hum).is_err());
+    }
+
+    #[test]
+    fn test_registry_normalizes_names() {
+        let mut registry = CharaRegistry::new();
+        registry
+            .register(CharacterProfile::new("Alice".to_string(), Race::Hum))
+            .unwrap();
+
+        // 前後の空白を除いた名前で重複判定する (JSON 由来などで未正規化の名前も含む)
+        let mut untrimmed = CharacterProfile::new("Alice".to_string(), Race::Elv);
+        untrimmed.name = "Alice ".to_string();
+        let err = registry.register(untrimmed).unwrap_err();
+        assert_eq!(err, "Character 'Alice' already exists");
+
+        let mut empty = CharacterProfile::new(String::new(), Race::Hum);
+        empty.name = "  ".to_string();
+        assert_eq!(
+            registry.register(empty).unwrap_err(),
+            "name must not be empty"
+        );
+
+        let mut bob = CharacterProfile::new("Bob".to_string(), Race::Tar);
+        bob.name = " Bob ".to_string();
+        registry.register(bob).unwrap();
+        assert_eq!(registry.list(), vec!["Alice", "Bob"]);
+        assert!(registry.get(" Alice").is_some());
+        assert!(registry.remove("Bob "));
+        assert_eq!(registry.list(), vec!["Alice"]);
+    }
+
+    #[test]
+    fn test_registry_with_capacity() {
+        let mut registry = CharaRegistry::with_capacity(2);
+        assert_eq!(registry.max(), Some(2));
+        assert_eq!(CharaRegistry::new().max(), None);
+        registry
+            .register(CharacterProfile::new("Alice".to_string(), Race::Hum))
+            .unwrap();
+        registry
+            .register(CharacterProfile::new("Bob".to_string(), Race::Elv))
+            .unwrap();
+
+        let result = registry.register(CharacterProfile::new("Carol".to_string(), Race::Tar));
+        assert_eq!(result.unwrap_err(), "registry is full");
+        assert!(registry.get("Carol").is_none());
+
+        // remove 後は再び登録できる
+        assert!(registry.remove("Alice"));
+        registry
+            .register(CharacterProfile::new("Carol".to_string(), Race::Tar))
+            .unwrap();
+        assert_eq!(registry.list(), vec!["Bob", "Carol"]);
+
+        // 上限はメモリを事前確保しないため、事実上の無制限として usize::MAX も指定できる
+        let mut unlimited = CharaRegistry::with_capacity(usize::MAX);
+        unlimited
+            .register(CharacterProfile::new("Alice".to_string(), Race::Hum))
+            .unwrap();
+        assert_eq!(unlimited.max(), Some(usize::MAX));
+    }
+
+    #[test]
+    fn test_registry_estimated_memory() {
+        let profile_size = size_of::<CharacterProfile>();
+        let mut registry = CharaRegistry::new();
+        assert_eq!(registry.estimated_memory(), 0);
+
+        registry
+            .register(CharacterProfile::new("Alice".to_string(), Race::Hum))
+            .unwrap();
+        assert_eq!(registry.estimated_memory(), profile_size + "Alice".len());
+
+        // スキルメリットはキー文字列を含めて加算
+        let mut bob = CharacterProfile::new("Bob".to_string(), Race::Elv);
+        bob.merit_points
+            .combat_skill_merits
+            .insert("sword".to_string(), 2);
+        let bob_size = profile_size + "Bob".len() + size_of::<(String, i32)>() + "sword".len();
+        assert_eq!(bob.estimated_memory(), bob_size);
+        registry.register(bob).unwrap();
+        assert_eq!(
+            registry.estimated_memory(),
+            profile_size + "Alice".len() + bob_size
+        );
+
+        assert_eq!(CharaRegistry::estimated_memory_for(0), 0);
+        assert_eq!(CharaRegistry::estimated_memory_for(100), 100 * profile_size);
+        assert_eq!(CharaRegistry::estimated_memory_for(usize::MAX), usize::MAX);
+    }
+
     #[test]
     fn test_registry_remove() {
         let mut registry = CharaRegistry::new();
@@ -351,6 +1590,118 @@ mod tests {
         assert_eq!(retrieved.job_levels[Job::War].level, 99);
     }
 
+    fn registry_with_three() -> CharaRegistry {
+        let mut registry = CharaRegistry::new();
+        let mut alice = CharacterProfile::new("Alice".to_string(), Race::Hum);
+        alice.set_job_level(Job::War, 99, 0);
+        let mut bob = CharacterProfile::new("Bob".to_string(), Race::Tar);
+        bob.set_job_level(Job::Blm, 99, 0);
+        let mut carol = CharacterProfile::new("Carol".to_string(), Race::Hum);
+        carol.set_job_level(Job::War, 30, 0);
+        carol.set_job_level(Job::Whm, 75, 0);
+        registry.register(alice).unwrap();
+        registry.register(bob).unwrap();
+        registry.register(carol).unwrap();
+        registry
+    }
+
+    fn names(profiles: Vec<&CharacterProfile>) -> Vec<&str> {
+        profiles.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_registry_iter() {
+        let registry = registry_with_three();
+        assert_eq!(registry.iter().count(), 3);
+        assert_eq!(
+            names(registry.iter().collect()),
+            vec!["Alice", "Bob", "Carol"]
+        );
+        let hum: Vec<_> = registry.iter().filter(|c| c.race == Race::Hum).collect();
+        assert_eq!(names(hum), vec!["Alice", "Carol"]);
+        assert_eq!(CharaRegistry::new().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_registry_find() {
+        let registry = registry_with_three();
+        let found = registry.find(|c| c.job_levels[Job::Whm].level >= 75);
+        assert_eq!(names(found), vec!["Carol"]);
+        assert!(registry.find(|c| c.name == "Unknown").is_empty());
+    }
+
+    #[test]
+    fn test_registry_by_race() {
+        let registry = registry_with_three();
+        assert_eq!(names(registry.by_race(Race::Hum)), vec!["Alice", "Carol"]);
+        assert_eq!(names(registry.by_race(Race::Tar)), vec!["Bob"]);
+        assert!(registry.by_race(Race::Gal).is_empty());
+    }
+
+    #[test]
+    fn test_registry_with_job_leveled() {
+        let registry = registry_with_three();
+        assert_eq!(
+            names(registry.with_job_leveled(Job::War)),
+            vec!["Alice", "Carol"]
+        );
+        assert_eq!(names(registry.with_job_leveled(Job::Blm)), vec!["Bob"]);
+        assert!(registry.with_job_leveled(Job::Run).is_empty());
+    }
+
+    #[test]
+    fn test_registry_save_and_load_json() {
+        let registry = registry_with_three();
+        let json = registry.save_to_json("2026-01-01T00:00:00Z").unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], REGISTRY_EXPORT_VERSION);
+        assert_eq!(value["exported_at"], "2026-01-01T00:00:00Z");
+        assert_eq!(value["characters"].as_array().unwrap().len(), 3);
+
+        let loaded = CharaRegistry::load_from_json(&json).unwrap();
+        assert_eq!(loaded.list(), vec!["Alice", "Bob", "Carol"]);
+        assert_eq!(loaded.get("Carol").unwrap().job_levels[Job::Whm].level, 75);
+    }
+
+    #[test]
+    fn test_registry_load_legacy_array_json() {
+        // メタ情報なし・schema_version なしのプロファイル配列
+        let mut alice =
+            serde_json::to_value(CharacterProfile::new("Alice".to_string(), Race::Hum)).unwrap();
+        alice.as_object_mut().unwrap().remove("schema_version");
+        let json = serde_json::Value::Array(vec![alice]).to_string();
+
+        let loaded = CharaRegistry::load_from_json(&json).unwrap();
+        assert_eq!(loaded.list(), vec!["Alice"]);
+        assert_eq!(
+            loaded.get("Alice").unwrap().schema_version,
+            CURRENT_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_registry_load_json_errors() {
+        let future =
+            serde_json::json!({ "version": REGISTRY_EXPORT_VERSION + 1, "characters": [] });
+        assert!(
+            CharaRegistry::load_from_json(&future.to_string())
+                .unwrap_err()
+                .contains("Unsupported export version")
+        );
+        assert!(CharaRegistry::load_from_json("42").is_err());
+
+        // 重複した名前は register と同じくエラー
+        let profile =
+            serde_json::to_value(CharacterProfile::new("Alice".to_string(), Race::Hum)).unwrap();
+        let json = serde_json::Value::Array(vec![profile.clone(), profile]).to_string();
+        assert!(
+            CharaRegistry::load_from_json(&json)
+                .unwrap_err()
+                .contains("already exists")
+        );
+    }
+
     #[test]
     fn test_registry_to_chara() {
         let mut registry = CharaRegistry::new();
@@ -362,7 +1713,7 @@ mod tests {
         let chara = registry
             .to_chara("Adventurer", Job::War, Some(Job::Drg))
             .unwrap();
-        assert_eq!(chara.status(StatusKind::Hp), 1945);
+        assert_eq!(chara.status(StatusKind::Hp), 1915);
     }
 
     #[test]
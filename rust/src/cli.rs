@@ -0,0 +1,549 @@
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand};
+use strum::VariantArray;
+
+use crate::chara::{BuildError, Chara};
+use crate::character_profile::{CharaRegistry, CharacterProfile};
+use crate::job::Job;
+use crate::race::Race;
+use crate::status::{MeritPoints, StatusKind};
+
+#[derive(Debug, Parser)]
+#[command(name = "ff11sim", about = "A simulator of FINAL FANTASY XI")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// 全ジョブのステータス (サポートなし・ML0) を一覧表示する
+    Table {
+        #[arg(long, value_enum)]
+        race: Race,
+        #[arg(long, default_value_t = 99, value_parser = clap::value_parser!(i32).range(1..=99))]
+        lv: i32,
+    },
+    /// 1 キャラクターのステータスを計算する
+    Calc {
+        #[arg(long, value_enum)]
+        race: Race,
+        #[arg(long)]
+        job: Job,
+        #[arg(long, default_value_t = 99, value_parser = clap::value_parser!(i32).range(1..=99))]
+        lv: i32,
+        #[arg(long, requires = "support_lv")]
+        support: Option<Job>,
+        #[arg(long, requires = "support", value_parser = clap::value_parser!(i32).range(1..=99))]
+        support_lv: Option<i32>,
+        #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(i32).range(0..=50))]
+        ml: i32,
+        /// メリットポイント (例: `str=3,hp=5,vit=2`)
+        #[arg(long, value_parser = parse_merit_points)]
+        merit: Option<MeritPoints>,
+    },
+    /// 登録済みキャラクターについて、指定ステータスを最大化するサポートジョブを提案する
+    Suggest {
+        /// `CharaRegistry::save_to_json` 形式の JSON ファイル
+        #[arg(long)]
+        file: PathBuf,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        main: Job,
+        /// 最大化するステータス (hp/mp/str/dex/vit/agi/int/mnd/chr)
+        #[arg(long, value_parser = parse_status_kind)]
+        maximize: StatusKind,
+        /// 表示する候補数
+        #[arg(long, default_value_t = 5)]
+        top: usize,
+    },
+    /// 種族・ジョブ・レベルを順に尋ねてステータスを表示する対話モード (`q` で終了)
+    Interactive,
+}
+
+pub fn run(cli: &Cli) -> Result<String, String> {
+    match &cli.command {
+        Command::Table { race, lv } => Ok(status_table(*race, *lv)?),
+        Command::Calc {
+            race,
+            job,
+            lv,
+            support,
+            support_lv,
+            ml,
+            merit,
+        } => {
+            let mut builder = Chara::builder()
+                .race(*race)
+                .main_job(*job, *lv)
+                .master_lv(*ml)
+                .merit_points(merit.clone().unwrap_or_default());
+            if let (Some(support), Some(support_lv)) = (support, support_lv) {
+                builder = builder.support_job(*support, *support_lv);
+            }
+            let chara = builder.build()?;
+            Ok(status_lines(&chara))
+        }
+        Command::Suggest {
+            file,
+            name,
+            main,
+            maximize,
+            top,
+        } => {
+            let json = std::fs::read_to_string(file)
+                .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+            let registry = CharaRegistry::load_from_json(&json)?;
+            let profile = registry
+                .get(name)
+                .ok_or_else(|| format!("Character '{}' not found", name))?;
+            suggest_supports(profile, *main, *maximize, *top)
+        }
+        Command::Interactive => {
+            interactive(io::stdin().lock(), &mut io::stdout())
+                .map_err(|e| format!("I/O error: {}", e))?;
+            Ok(String::new())
+        }
+    }
+}
+
+/// 対話モードの 1 回分の入力。`q` で終了を表す
+#[derive(Debug, PartialEq)]
+pub enum Answer<T> {
+    Value(T),
+    Quit,
+}
+
+/// 入力行を `q` (終了) か `parse` の結果に振り分ける
+fn parse_answer<T>(
+    line: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Answer<T>, String> {
+    let line = line.trim();
+    if line.eq_ignore_ascii_case("q") {
+        return Ok(Answer::Quit);
+    }
+    parse(line).map(Answer::Value)
+}
+
+pub fn parse_race_input(line: &str) -> Result<Answer<Race>, String> {
+    parse_answer(line, Race::from_str)
+}
+
+pub fn parse_job_input(line: &str) -> Result<Answer<Job>, String> {
+    parse_answer(line, Job::from_str)
+}
+
+/// サポートジョブの入力。空行または `-` はサポートなし
+pub fn parse_support_input(line: &str) -> Result<Answer<Option<Job>>, String> {
+    parse_answer(line, |s| match s {
+        "" | "-" => Ok(None),
+        _ => Job::from_str(s).map(Some),
+    })
+}
+
+/// レベルの入力 (1〜99)。空行は `default`
+pub fn parse_level_input(line: &str, default: i32) -> Result<Answer<i32>, String> {
+    parse_answer(line, |s| {
+        if s.is_empty() {
+            return Ok(default);
+        }
+        let lv: i32 = s.parse().map_err(|_| format!("Invalid level: {}", s))?;
+        if !(1..=99).contains(&lv) {
+            return Err(format!("level must be between 1 and 99: {}", lv));
+        }
+        Ok(lv)
+    })
+}
+
+/// `prompt` を表示して 1 行読み、パースできるまで再入力を促す。
+/// `q` または入力終端 (EOF) では `None` を返す。
+fn ask<T, R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    prompt: &str,
+    parse: impl Fn(&str) -> Result<Answer<T>, String>,
+) -> io::Result<Option<T>> {
+    loop {
+        write!(output, "{}: ", prompt)?;
+        output.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        match parse(&line) {
+            Ok(Answer::Value(value)) => return Ok(Some(value)),
+            Ok(Answer::Quit) => return Ok(None),
+            Err(e) => writeln!(output, "error: {}", e)?,
+        }
+    }
+}
+
+/// 種族 → メインジョブ → レベル → サポートジョブ → サポートレベルの順に尋ね、
+/// ステータスを表示する。`q` が入力されるまで別の構成を繰り返し試せる。
+pub fn interactive<R: BufRead, W: Write>(mut input: R, output: &mut W) -> io::Result<()> {
+    let input = &mut input;
+    writeln!(output, "Enter 'q' to quit.")?;
+    loop {
+        let Some(race) = ask(input, output, "Race", parse_race_input)? else {
+            return Ok(());
+        };
+        let Some(job) = ask(input, output, "Job", parse_job_input)? else {
+            return Ok(());
+        };
+        let Some(lv) = ask(input, output, "Level [99]", |s| parse_level_input(s, 99))? else {
+            return Ok(());
+        };
+        let Some(support) = ask(input, output, "Support job [-]", parse_support_input)? else {
+            return Ok(());
+        };
+        let mut builder = Chara::builder().race(race).main_job(job, lv).master_lv(0);
+        if let Some(support) = support {
+            let default = (lv / 2).max(1);
+            let prompt = format!("Support level [{}]", default);
+            let Some(support_lv) = ask(input, output, &prompt, |s| parse_level_input(s, default))?
+            else {
+                return Ok(());
+            };
+            builder = builder.support_job(support, support_lv);
+        }
+        match builder.build() {
+            Ok(chara) => write!(output, "{}", status_lines(&chara))?,
+            Err(e) => writeln!(output, "error: {}", e)?,
+        }
+        writeln!(output)?;
+    }
+}
+
+/// `rank_supports` の上位 `top` 件を 1 行 1 候補で返す。
+/// 育成済みのサポートジョブが無い場合はその旨を先頭に表示する (サポートなしは常に候補に含まれる)。
+pub fn suggest_supports(
+    profile: &CharacterProfile,
+    main_job: Job,
+    kind: StatusKind,
+    top: usize,
+) -> Result<String, String> {
+    let ranked = profile.rank_supports(main_job, kind)?;
+    let mut out = String::new();
+    if ranked.len() == 1 {
+        out.push_str("No leveled support job\n");
+    }
+    for (i, (sub, value)) in ranked.iter().take(top).enumerate() {
+        let sub = sub.map_or("-".to_string(), |job| format!("{:?}", job));
+        out.push_str(&format!(
+            "{:>2}. {:?}/{:<4}{} {}\n",
+            i + 1,
+            main_job,
+            sub,
+            format!("{:?}", kind).to_uppercase(),
+            value
+        ));
+    }
+    Ok(out)
+}
+
+/// `str` / `HP` などのステータス名をパースする (大文字小文字は区別しない)
+pub fn parse_status_kind(s: &str) -> Result<StatusKind, String> {
+    StatusKind::VARIANTS
+        .iter()
+        .copied()
+        .find(|kind| format!("{:?}", kind).eq_ignore_ascii_case(s.trim()))
+        .ok_or_else(|| format!("Unknown status: {}", s))
+}
+
+/// `str=3,hp=5,vit=2` 形式のメリット指定をパースし、ランク上限を検証する。
+/// キーは hp/mp/str/dex/vit/agi/int/mnd/chr (大文字小文字は区別しない)。
+pub fn parse_merit_points(s: &str) -> Result<MeritPoints, String> {
+    let mut merit = MeritPoints::default();
+    for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        let (key, value) = item
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid merit entry (expected key=value): {}", item))?;
+        let rank: i32 = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid merit rank: {}", item))?;
+        let slot = match key.trim().to_lowercase().as_str() {
+            "hp" => &mut merit.hp,
+            "mp" => &mut merit.mp,
+            "str" => &mut merit.str_,
+            "dex" => &mut merit.dex,
+            "vit" => &mut merit.vit,
+            "agi" => &mut merit.agi,
+            "int" => &mut merit.int,
+            "mnd" => &mut merit.mnd,
+            "chr" => &mut merit.chr,
+            _ => return Err(format!("Unknown merit key: {}", key)),
+        };
+        *slot = rank;
+    }
+    merit.validate()?;
+    Ok(merit)
+}
+
+/// 1 キャラクターの全ステータスを 1 行 1 ステータスで返す
+pub fn status_lines(chara: &Chara) -> String {
+    StatusKind::VARIANTS
+        .iter()
+        .map(|&kind| {
+            format!(
+                "{:<4}{:>5}\n",
+                format!("{:?}", kind).to_uppercase(),
+                chara.status(kind)
+            )
+        })
+        .collect()
+}
+
+/// 全 22 ジョブのステータスを 1 ジョブ 1 行で返す (Job 定義順)。
+/// MP を持たないジョブの MP は `-` と表示する。lv が範囲外なら `BuildError`。
+pub fn status_table_rows(race: Race, lv: i32) -> Result<Vec<String>, BuildError> {
+    Job::VARIANTS
+        .iter()
+        .map(|&job| {
+            let chara = Chara::builder()
+                .race(race)
+                .main_job(job, lv)
+                .master_lv(0)
+                .build()?;
+            let cols: Vec<String> = StatusKind::VARIANTS
+                .iter()
+                .map(|&kind| {
+                    if kind == StatusKind::Mp && job.status_grade(kind).is_none() {
+                        format!("{:>5}", "-")
+                    } else {
+                        format!("{:>5}", chara.status(kind))
+                    }
+                })
+                .collect();
+            Ok(format!("{:<4}{}", format!("{:?}", job), cols.join("")))
+        })
+        .collect()
+}
+
+/// ヘッダ行付きのステータス一覧表
+pub fn status_table(race: Race, lv: i32) -> Result<String, BuildError> {
+    let header: String = StatusKind::VARIANTS
+        .iter()
+        .map(|kind| format!("{:>5}", format!("{:?}", kind).to_uppercase()))
+        .collect();
+    let mut out = format!("{:<4}{}\n", "Job", header);
+    for row in status_table_rows(race, lv)? {
+        out.push_str(&row);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_table_rows() {
+        let rows = status_table_rows(Race::Hum, 99).unwrap();
+        assert_eq!(rows.len(), 22);
+        assert_eq!(status_table(Race::Hum, 99).unwrap().lines().count(), 1 + 22);
+        assert_eq!(
+            status_table(Race::Hum, 100),
+            Err(BuildError::MainLvOutOfRange)
+        );
+
+        // Hum War99 (ML0) = HP 1340 / MP なし / STR 82
+        let war: Vec<&str> = rows[0].split_whitespace().collect();
+        assert_eq!(war[..4], ["War", "1340", "-", "82"]);
+        // Blm は MP を持つ
+        let blm: Vec<&str> = rows[Job::Blm as usize].split_whitespace().collect();
+        assert_eq!(blm[..3], ["Blm", "810", "1160"]);
+    }
+
+    #[test]
+    fn test_cli_parse_table() {
+        let cli = Cli::try_parse_from(["ff11sim", "table", "--race", "hum", "--lv", "99"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Table {
+                race: Race::Hum,
+                lv: 99
+            }
+        ));
+        assert!(Cli::try_parse_from(["ff11sim", "table", "--race", "hum", "--lv", "100"]).is_err());
+    }
+
+    #[test]
+    fn test_suggest_supports() {
+        let mut profile = CharacterProfile::new("Alice".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        profile.set_job_level(Job::Drg, 59, 0);
+        profile.set_job_level(Job::Blm, 59, 0);
+        profile.set_job_level(Job::Mnk, 59, 0);
+
+        // 表示順は rank_supports と一致する
+        let ranked = profile.rank_supports(Job::War, StatusKind::Str).unwrap();
+        let out = suggest_supports(&profile, Job::War, StatusKind::Str, 3).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for (line, (sub, value)) in lines.iter().zip(&ranked) {
+            let sub = sub.map_or("-".to_string(), |job| format!("{:?}", job));
+            assert!(line.contains(&format!("War/{}", sub)), "{}", line);
+            assert!(line.ends_with(&format!("STR {}", value)), "{}", line);
+        }
+        assert!(lines[0].starts_with(" 1. "));
+
+        // 育成済みサポートが無い場合
+        let mut solo = CharacterProfile::new("Solo".to_string(), Race::Hum);
+        solo.set_job_level(Job::War, 99, 0);
+        let out = suggest_supports(&solo, Job::War, StatusKind::Str, 5).unwrap();
+        assert_eq!(out, "No leveled support job\n 1. War/-   STR 82\n");
+    }
+
+    #[test]
+    fn test_cli_parse_suggest() {
+        let cli = Cli::try_parse_from([
+            "ff11sim",
+            "suggest",
+            "--file",
+            "chars.json",
+            "--name",
+            "Alice",
+            "--main",
+            "war",
+            "--maximize",
+            "str",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Suggest {
+                main,
+                maximize,
+                top,
+                ..
+            } => {
+                assert_eq!(main, Job::War);
+                assert_eq!(maximize, StatusKind::Str);
+                assert_eq!(top, 5);
+            }
+            _ => panic!("unexpected command"),
+        }
+        assert!(parse_status_kind("luck").is_err());
+    }
+
+    #[test]
+    fn test_parse_merit_points() {
+        let merit = parse_merit_points("str=3, HP=5,vit=2").unwrap();
+        assert_eq!((merit.str_, merit.hp, merit.vit, merit.dex), (3, 5, 2, 0));
+        assert!(parse_merit_points("").is_ok());
+
+        let err = parse_merit_points("luck=1").unwrap_err();
+        assert!(err.contains("Unknown merit key"), "{}", err);
+        let err = parse_merit_points("str=16").unwrap_err();
+        assert!(err.contains("between 0 and 15"), "{}", err);
+        assert!(parse_merit_points("str").is_err());
+        assert!(parse_merit_points("str=x").is_err());
+    }
+
+    #[test]
+    fn test_parse_interactive_input() {
+        assert_eq!(parse_race_input(" elv\n"), Ok(Answer::Value(Race::Elv)));
+        assert_eq!(parse_race_input("タルタル"), Ok(Answer::Value(Race::Tar)));
+        assert_eq!(parse_race_input("Q"), Ok(Answer::Quit));
+        assert!(parse_race_input("moogle").is_err());
+
+        assert_eq!(parse_job_input("Warrior"), Ok(Answer::Value(Job::War)));
+        assert_eq!(parse_job_input("q"), Ok(Answer::Quit));
+        assert!(parse_job_input("").is_err());
+
+        assert_eq!(parse_support_input(""), Ok(Answer::Value(None)));
+        assert_eq!(parse_support_input("-"), Ok(Answer::Value(None)));
+        assert_eq!(
+            parse_support_input("sam"),
+            Ok(Answer::Value(Some(Job::Sam)))
+        );
+        assert!(parse_support_input("xyz").is_err());
+
+        assert_eq!(parse_level_input("", 99), Ok(Answer::Value(99)));
+        assert_eq!(parse_level_input("75", 99), Ok(Answer::Value(75)));
+        assert!(parse_level_input("0", 99).is_err());
+        assert!(parse_level_input("100", 99).is_err());
+        assert!(parse_level_input("abc", 99).is_err());
+    }
+
+    #[test]
+    fn test_interactive_session() {
+        // 不正入力は再入力、計算後は次の構成へ、q で終了
+        let input = "moogle\nhum\nwar\n\n\nelv\nq\n";
+        let mut output = Vec::new();
+        interactive(input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("error: Unknown race: moogle"), "{}", output);
+        // Hum War99 (ML0) は `calc` と同じ結果
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        assert!(output.contains(&status_lines(&chara)), "{}", output);
+        assert_eq!(output.matches("Race: ").count(), 3);
+        assert!(output.ends_with("Job: "), "{}", output);
+
+        // EOF でも終了する
+        let mut output = Vec::new();
+        interactive("hum\nwar\n".as_bytes(), &mut output).unwrap();
+    }
+
+    #[test]
+    fn test_cli_calc_with_merit() {
+        // character_profile の test_to_chara_with_merit_points と同じ構成
+        let cli = Cli::try_parse_from([
+            "ff11sim",
+            "calc",
+            "--race",
+            "hum",
+            "--job",
+            "war",
+            "--merit",
+            "str=3,hp=5",
+        ])
+        .unwrap();
+        let out = run(&cli).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0].split_whitespace().collect::<Vec<_>>(),
+            ["HP", "1390"]
+        );
+        assert_eq!(
+            lines[2].split_whitespace().collect::<Vec<_>>(),
+            ["STR", "85"]
+        );
+
+        // 未知キー・上限超過はパースエラー
+        for merit in ["luck=1", "vit=20"] {
+            assert!(
+                Cli::try_parse_from([
+                    "ff11sim", "calc", "--race", "hum", "--job", "war", "--merit", merit,
+                ])
+                .is_err()
+            );
+        }
+        // --support は --support-lv とセット
+        assert!(
+            Cli::try_parse_from([
+                "ff11sim",
+                "calc",
+                "--race",
+                "hum",
+                "--job",
+                "war",
+                "--support",
+                "sam",
+            ])
+            .is_err()
+        );
+    }
+}
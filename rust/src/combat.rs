@@ -0,0 +1,197 @@
+use crate::chara::Chara;
+use crate::job::JobTrait;
+use crate::job_points::{calc_gift_bonuses, calc_jp_category_bonuses, GiftBonuses};
+use crate::status::{calc_magic_attack, ModKind};
+
+/// 3 秒 (1 tick) ごとの自然回復量
+impl Chara {
+    /// Refresh 総合値 (MP 回復/tick) = 装備 + オートリフレシュ特性。
+    /// MP を持たないメインジョブでも値はそのまま返す (MP 0 の扱いは呼び出し側で行う)。
+    pub fn refresh_total(&self) -> i32 {
+        self.bonus_stats.refresh + self.job_trait_total(JobTrait::AutoRefresh)
+    }
+
+    /// Regen 総合値 (HP 回復/tick) = 装備 + オートリジェネ特性
+    pub fn regen_total(&self) -> i32 {
+        self.bonus_stats.regen + self.job_trait_total(JobTrait::AutoRegen)
+    }
+}
+
+/// 二刀流による攻撃間隔短縮
+impl Chara {
+    /// 二刀流の攻撃間隔短縮 (%)。現状は特性 (メイン・サポートの強い方) 由来のみで、
+    /// 装備の Dual Wield+ は `BonusStats` にフィールドを追加してここで加算する想定。
+    pub fn dual_wield_reduction(&self) -> i32 {
+        self.job_trait_total(JobTrait::DualWield)
+    }
+}
+
+/// 魔法攻撃力
+impl Chara {
+    /// 魔法攻撃力アップの合計 = 特性 + ギフト + JP カテゴリ (装備の魔攻は含まない)
+    pub fn magic_attack_bonus(&self) -> i32 {
+        let total_jp = self.job_points.total_jp_spent();
+        self.job_trait_total(JobTrait::MagicAttackBonus)
+            + calc_gift_bonuses(self.main_job, total_jp).magic_attack
+            + self.job_point_category_bonuses().magic_attack
+    }
+
+    /// 魔法攻撃力の総合値 = 基準値 100 + 装備 + `magic_attack_bonus` + 装備のステータス変換。
+    /// wasm の `StatusResult.magic_attack` もこの値を返す。
+    pub fn magic_attack(&self) -> i32 {
+        calc_magic_attack(self.bonus_stats.magic_attack)
+            + self.magic_attack_bonus()
+            + self
+                .bonus_stats
+                .conversion_total(ModKind::MagicAttack, |kind| self.status(kind))
+    }
+}
+
+/// ジョブポイントのカテゴリ振り分けによる戦闘ステータス
+impl Chara {
+    /// メインジョブのカテゴリ振り分けによる直接効果 (ギフトは含まない)。
+    /// ゲームの JP カテゴリに基本能力値 (STR など) を上げるものは無いため、効果は攻撃力・命中などの
+    /// 派生値 (`StatusResult` の総合値) にのみ加算され、`status()` には影響しない。
+    pub fn job_point_category_bonuses(&self) -> GiftBonuses {
+        calc_jp_category_bonuses(self.main_job, &self.job_points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chara::Chara;
+    use crate::job::Job;
+    use crate::job_points::{calc_gift_bonuses, JobPointCategory};
+    use crate::race::Race;
+    use crate::status::{BonusStats, StatusKind};
+    use enum_map::EnumMap;
+    use strum::VariantArray;
+
+    fn chara(job: Job, lv: i32, bonus_stats: BonusStats) -> Chara {
+        Chara::builder()
+            .race(Race::Hum)
+            .main_job(job, lv)
+            .master_lv(0)
+            .bonus_stats(bonus_stats)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_refresh_regen_zero_without_gear_or_trait() {
+        let war = chara(Job::War, 99, BonusStats::default());
+        assert_eq!(war.refresh_total(), 0);
+        assert_eq!(war.regen_total(), 0);
+    }
+
+    #[test]
+    fn test_refresh_regen_sum_gear_and_trait() {
+        let gear = BonusStats {
+            refresh: 3,
+            regen: 5,
+            ..BonusStats::default()
+        };
+        // 装備のみ
+        let war = chara(Job::War, 99, gear.clone());
+        assert_eq!(war.refresh_total(), 3);
+        assert_eq!(war.regen_total(), 5);
+        // Smn90: オートリフレシュ rank 2 (+2) + 装備 3
+        assert_eq!(chara(Job::Smn, 90, gear.clone()).refresh_total(), 2 + 3);
+        // Run95: オートリジェネ rank 3 (+3) + 装備 5
+        assert_eq!(chara(Job::Run, 95, gear).regen_total(), 3 + 5);
+    }
+
+    #[test]
+    fn test_dual_wield_reduction() {
+        // War は二刀流を持たない
+        assert_eq!(
+            chara(Job::War, 99, BonusStats::default()).dual_wield_reduction(),
+            0
+        );
+        // Nin: Lv10/25/45/65/83 で rank 1〜5 (累積 10/15/25/30/35%)
+        for (lv, expected) in [(9, 0), (10, 10), (25, 15), (50, 25), (65, 30), (99, 35)] {
+            assert_eq!(
+                chara(Job::Nin, lv, BonusStats::default()).dual_wield_reduction(),
+                expected,
+                "Nin{}",
+                lv
+            );
+        }
+        // サポートの二刀流も有効: War99/Nin49 は rank 3
+        let war_nin = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Nin, 49)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        assert_eq!(war_nin.dual_wield_reduction(), 25);
+    }
+
+    #[test]
+    fn test_magic_attack() {
+        let war = chara(Job::War, 99, BonusStats::default());
+        let blm = chara(Job::Blm, 99, BonusStats::default());
+        // War: 特性・ギフトなし
+        assert_eq!(war.magic_attack_bonus(), 0);
+        assert_eq!(war.magic_attack(), 100);
+        // Blm99: 魔法攻撃力アップ rank 6 (+40)
+        assert_eq!(blm.magic_attack_bonus(), 40);
+        assert_eq!(blm.magic_attack(), 140);
+        assert!(blm.magic_attack() > war.magic_attack());
+
+        // 装備の魔攻はそのまま加算される
+        let gear = BonusStats {
+            magic_attack: 30,
+            ..BonusStats::default()
+        };
+        assert_eq!(
+            chara(Job::Blm, 99, gear).magic_attack(),
+            blm.magic_attack() + 30
+        );
+
+        // JP カテゴリ (Rdm Category5 は魔法攻撃力 +1/rank) も加算される
+        let mut allocation = EnumMap::default();
+        allocation[JobPointCategory::Category5] = 10;
+        let rdm = |allocation| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::Rdm, 99)
+                .master_lv(0)
+                .job_point_allocation(allocation)
+                .build()
+                .unwrap()
+        };
+        let plain = rdm(EnumMap::default());
+        let rdm = rdm(allocation);
+        // JP を使った分のギフトも加わる
+        let gift = calc_gift_bonuses(Job::Rdm, rdm.job_points.total_jp_spent()).magic_attack;
+        assert_eq!(rdm.magic_attack(), plain.magic_attack() + 10 + gift);
+    }
+
+    #[test]
+    fn test_job_point_category_bonuses() {
+        let mut allocation = EnumMap::default();
+        allocation[JobPointCategory::Category3] = 20;
+        allocation[JobPointCategory::Category5] = 10;
+        let rdm = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::Rdm, 99)
+            .master_lv(0)
+            .job_point_allocation(allocation)
+            .build()
+            .unwrap();
+        // Rdm: Category3 は魔法命中、Category5 は魔法攻撃力 (+1/rank)
+        let bonuses = rdm.job_point_category_bonuses();
+        assert_eq!(bonuses.magic_accuracy, 20);
+        assert_eq!(bonuses.magic_attack, 10);
+        assert_eq!(bonuses.physical_attack, 0);
+
+        // 振り分けなしとの差は派生値のみで、基本能力値は変わらない
+        let plain = chara(Job::Rdm, 99, BonusStats::default());
+        assert_eq!(plain.job_point_category_bonuses().magic_attack, 0);
+        for &kind in StatusKind::VARIANTS {
+            assert_eq!(rdm.status(kind), plain.status(kind));
+        }
+    }
+}
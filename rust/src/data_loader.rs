@@ -22,7 +22,6 @@ use crate::status::{Grade, StatusKind};
 /// 全 JSON ファイル共通のラッパ形式
 #[derive(Debug, Clone, Deserialize)]
 pub struct DataFile<T> {
-    #[allow(dead_code)]
     pub version: u32,
     pub data: T,
 }
@@ -130,6 +129,23 @@ pub static GRADE_COEFFICIENTS: LazyLock<GradeCoefficients> = LazyLock::new(|| {
     .data
 });
 
+/// ステータス計算に使うグレード関連 JSON の `version` を
+/// `grade_coefficients.race_status_grades.job_status_grades` の順に `.` で連結した文字列 (例: "1.1.1")
+pub static GRADE_DATA_VERSION: LazyLock<String> = LazyLock::new(|| {
+    [
+        include_str!("../../data/grade_coefficients.json"),
+        include_str!("../../data/race_status_grades.json"),
+        include_str!("../../data/job_status_grades.json"),
+    ]
+    .map(|json| {
+        serde_json::from_str::<DataFile<serde::de::IgnoredAny>>(json)
+            .expect("grade data parse failed")
+            .version
+            .to_string()
+    })
+    .join(".")
+});
+
 /// ジョブ × スキル ランク行列。未習得は `None`。
 pub static JOB_SKILL_RANKS: LazyLock<EnumMap<Job, EnumMap<SkillKind, Option<SkillRank>>>> =
     LazyLock::new(|| {
@@ -195,4 +211,9 @@ mod tests {
     fn equipment_slots_count() {
         assert_eq!(EQUIPMENT_SLOTS_META.len(), 16);
     }
+
+    #[test]
+    fn grade_data_version_joins_file_versions() {
+        assert_eq!(GRADE_DATA_VERSION.as_str(), "1.1.1");
+    }
 }
@@ -0,0 +1,175 @@
+use std::fmt;
+
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+use crate::chara::BuildError;
+use crate::character_profile::ProfileError;
+
+/// エラー種別。JS 側ではこの `code` で分岐する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// 種族名が不正
+    InvalidRace,
+    /// ジョブ名が不正
+    InvalidJob,
+    /// 入力オブジェクトの形式が不正 (デシリアライズ失敗・未知のキーなど)
+    InvalidInput,
+    /// レベル・メリットなどが範囲外
+    OutOfRange,
+    /// 必須項目が未指定
+    MissingField,
+    /// 未習得 (レベル 0) のジョブを指定した
+    JobNotLeveled,
+    /// 結果のシリアライズ失敗などの内部エラー
+    Internal,
+}
+
+/// wasm API のエラー。JS には `{ code, message, field? }` のオブジェクトとして返す
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    /// 従来の平文エラーと同じ文言
+    pub message: String,
+    /// エラーの原因となった入力項目 (例: "support_job")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            field: None,
+        }
+    }
+
+    pub fn with_field(mut self, field: &str) -> Self {
+        self.field = Some(field.to_string());
+        self
+    }
+
+    /// 結果のシリアライズ失敗などの内部エラー
+    pub fn internal(e: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::Internal, e.to_string())
+    }
+}
+
+impl From<BuildError> for ApiError {
+    fn from(e: BuildError) -> Self {
+        let code = if e.is_out_of_range() {
+            ErrorCode::OutOfRange
+        } else {
+            ErrorCode::MissingField
+        };
+        Self::new(code, e.to_string()).with_field(e.field())
+    }
+}
+
+impl From<ProfileError> for ApiError {
+    fn from(e: ProfileError) -> Self {
+        match e {
+            ProfileError::NotLeveled { support, .. } => {
+                let field = if support { "support_job" } else { "main_job" };
+                Self::new(ErrorCode::JobNotLeveled, e.to_string()).with_field(field)
+            }
+            ProfileError::MainLvOverrideOutOfRange { .. } => {
+                Self::new(ErrorCode::OutOfRange, e.to_string()).with_field("main_lv_override")
+            }
+            ProfileError::Build(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<ApiError> for JsValue {
+    fn from(e: ApiError) -> Self {
+        serde_wasm_bindgen::to_value(&e).unwrap_or_else(|_| JsValue::from_str(&e.message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::VariantArray;
+
+    use super::*;
+    use crate::job::Job;
+
+    #[test]
+    fn test_from_build_error() {
+        let e = ApiError::from(BuildError::SupportLvOutOfRange);
+        assert_eq!(e.code, ErrorCode::OutOfRange);
+        assert_eq!(e.field.as_deref(), Some("support_lv"));
+        assert_eq!(e.message, "support_lv must be between 1 and 99");
+
+        let e = ApiError::from(BuildError::MissingRace);
+        assert_eq!(e.code, ErrorCode::MissingField);
+        assert_eq!(e.field.as_deref(), Some("race"));
+
+        // 全バリアントに field が付く
+        for &e in BuildError::VARIANTS {
+            assert!(ApiError::from(e).field.is_some(), "{:?}", e);
+        }
+    }
+
+    #[test]
+    fn test_from_profile_error() {
+        let e = ApiError::from(ProfileError::NotLeveled {
+            job: Job::Thf,
+            support: true,
+        });
+        assert_eq!(e.code, ErrorCode::JobNotLeveled);
+        assert_eq!(e.field.as_deref(), Some("support_job"));
+        assert_eq!(e.message, "Support job Thf is not leveled");
+
+        let e = ApiError::from(ProfileError::NotLeveled {
+            job: Job::War,
+            support: false,
+        });
+        assert_eq!(e.code, ErrorCode::JobNotLeveled);
+        assert_eq!(e.field.as_deref(), Some("main_job"));
+        assert_eq!(e.message, "War is not leveled");
+
+        let e = ApiError::from(ProfileError::MainLvOverrideOutOfRange {
+            job: Job::War,
+            max: 75,
+            lv: 76,
+        });
+        assert_eq!(e.code, ErrorCode::OutOfRange);
+        assert_eq!(e.field.as_deref(), Some("main_lv_override"));
+
+        // build() 由来のエラーは BuildError と同じ分類になる
+        for &e in BuildError::VARIANTS {
+            assert_eq!(ApiError::from(ProfileError::Build(e)), ApiError::from(e));
+        }
+    }
+
+    #[test]
+    fn test_serialize_shape() {
+        let e = ApiError::new(ErrorCode::JobNotLeveled, "Support job Thf is not leveled")
+            .with_field("support_job");
+        assert_eq!(
+            serde_json::to_value(&e).unwrap(),
+            serde_json::json!({
+                "code": "JOB_NOT_LEVELED",
+                "message": "Support job Thf is not leveled",
+                "field": "support_job",
+            })
+        );
+        // field なしの場合はキー自体を省略
+        let e = ApiError::new(ErrorCode::InvalidRace, "Invalid race");
+        assert_eq!(
+            serde_json::to_value(&e).unwrap(),
+            serde_json::json!({ "code": "INVALID_RACE", "message": "Invalid race" })
+        );
+    }
+}
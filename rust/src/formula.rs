@@ -0,0 +1,107 @@
+//! グレード係数の 1 行からステータスを計算する式。
+//! crate 内の他モジュールに依存しないため、`build.rs` も `#[path]` でこのファイルを取り込み、
+//! 事前計算テーブル (`precomputed`) を同じ式で生成する。
+
+/// グレードによる成長が止まるレベル。
+/// これを超えるレベル (超界など) を渡しても Lv99 と同じ値とし、
+/// 99 以降の成長はマスターレベル (`calc_master_lv_bonus`) で加算する。
+pub const MAX_GROWTH_LV: i32 = 99;
+
+/// レベル帯ごとの成長項: (開始 Lv, 終了 Lv, 係数行の列)。
+/// 各項は `係数 × 帯内で到達したレベル数`。
+pub const LEVEL_BANDS: [(i32, i32, usize); 3] = [(2, 60, 1), (61, 75, 2), (76, MAX_GROWTH_LV, 3)];
+
+/// HP/MP のみに加わる 30+ 項の開始 Lv。`MAX_GROWTH_LV` まで。
+pub const HPMP_30PLUS_START: i32 = 31;
+
+/// `calc_status` の端数処理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingStrategy {
+    /// レベル帯ごとの項を個別に 0.5 単位で切り捨ててから合算する (現行方式)
+    #[default]
+    PerBand,
+    /// 各項を丸めずに合算し、最後に 1 回だけ 0.5 単位で切り捨てる
+    Final,
+}
+
+/// `start..=end` のうち lv までに到達したレベル数
+pub fn levels_in_band(lv: i32, start: i32, end: i32) -> i32 {
+    (lv.min(end) - start + 1).max(0)
+}
+
+/// 0.5 単位の切り捨て (例: 0.9 → 0.5、1.4 → 1.0)
+pub fn floor_half(x: f32) -> f32 {
+    (x * 2.0).floor() / 2.0
+}
+
+/// 係数行 `row` ([Base, Lv2-60, Lv61-75, Lv76-99]) によるステータス値。
+/// HP/MP は `coef_30plus` に 30+ 係数を渡し、30+ 項も加える (基本ステータスは `None`)。
+/// lv 0 (未育成) は 0。
+pub fn status_from_row(
+    row: &[f32; 4],
+    coef_30plus: Option<f32>,
+    lv: i32,
+    rounding: RoundingStrategy,
+) -> f32 {
+    if lv == 0 {
+        return 0.0;
+    }
+
+    let mut terms: Vec<f32> = LEVEL_BANDS
+        .iter()
+        .map(|&(start, end, column)| row[column] * levels_in_band(lv, start, end) as f32)
+        .collect();
+    if let Some(coef) = coef_30plus {
+        terms.push(coef * levels_in_band(lv, HPMP_30PLUS_START, MAX_GROWTH_LV) as f32);
+    }
+
+    match rounding {
+        // truncate for each term with 0.5
+        RoundingStrategy::PerBand => row[0] + terms.into_iter().map(floor_half).sum::<f32>(),
+        RoundingStrategy::Final => floor_half(row[0] + terms.into_iter().sum::<f32>()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floor_half() {
+        assert_eq!(floor_half(0.0), 0.0);
+        assert_eq!(floor_half(0.4), 0.0);
+        assert_eq!(floor_half(0.5), 0.5);
+        assert_eq!(floor_half(0.9), 0.5);
+        assert_eq!(floor_half(1.0), 1.0);
+        assert_eq!(floor_half(20.65), 20.5);
+        assert_eq!(floor_half(9.36), 9.0);
+    }
+
+    #[test]
+    fn test_levels_in_band() {
+        assert_eq!(levels_in_band(1, 2, 60), 0);
+        assert_eq!(levels_in_band(2, 2, 60), 1);
+        assert_eq!(levels_in_band(99, 2, 60), 59);
+        assert_eq!(levels_in_band(70, 61, 75), 10);
+        assert_eq!(levels_in_band(99, 76, i32::MAX), 24);
+        assert_eq!(levels_in_band(30, 31, i32::MAX), 0);
+    }
+
+    #[test]
+    fn test_status_from_row() {
+        // HP グレード D: 14 + 6*59 + 3*15 + 3*24 + 30+(0*69) = 485
+        let row = [14.0, 6.0, 3.0, 3.0];
+        let hp = |lv| status_from_row(&row, Some(0.0), lv, RoundingStrategy::PerBand);
+        assert_eq!(hp(99), 485.0);
+        assert_eq!(hp(0), 0.0);
+        // 上限を超えるレベルは Lv99 と同じ
+        assert_eq!(hp(150), 485.0);
+
+        // 30+ 係数は HP/MP のみ: STR グレード D 3 + floor_half(0.35*59) + floor_half(0.34*15) + floor_half(0.39*24)
+        let row = [3.0, 0.35, 0.34, 0.39];
+        assert_eq!(
+            status_from_row(&row, None, 99, RoundingStrategy::PerBand),
+            3.0 + 20.5 + 5.0 + 9.0
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use std::str::FromStr;
+
 use crate::data_loader::JOB_STATUS_GRADES;
-use crate::status::{Grade, StatusKind};
-use enum_map::Enum;
+use crate::skills::{job_skill_cap, job_skill_rank, SkillKind, WeaponType};
+use crate::status::{calc_status, Grade, StatusKind};
+use enum_map::{Enum, EnumMap};
 use serde::{Deserialize, Serialize};
 use strum::{EnumCount, EnumIter, VariantArray};
 
@@ -43,10 +46,146 @@ pub enum Job {
     Run,
 }
 
+/// ジョブの大まかな役割分類
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumCount,
+    EnumIter,
+    VariantArray,
+    Enum,
+    Serialize,
+    Deserialize,
+)]
+pub enum JobCategory {
+    /// 近接物理
+    Melee,
+    /// 魔法
+    Magic,
+    /// 遠隔物理
+    Ranged,
+}
+
 impl Job {
+    /// ジョブの役割分類
+    pub fn category(&self) -> JobCategory {
+        match self {
+            Job::Whm | Job::Blm | Job::Rdm | Job::Brd | Job::Smn | Job::Sch | Job::Geo => {
+                JobCategory::Magic
+            }
+            Job::Rng | Job::Cor => JobCategory::Ranged,
+            _ => JobCategory::Melee,
+        }
+    }
+
+    /// 日本語のジョブ名
+    pub fn name_ja(&self) -> &'static str {
+        match self {
+            Job::War => "戦士",
+            Job::Mnk => "モンク",
+            Job::Whm => "白魔道士",
+            Job::Blm => "黒魔道士",
+            Job::Rdm => "赤魔道士",
+            Job::Thf => "シーフ",
+            Job::Pld => "ナイト",
+            Job::Drk => "暗黒騎士",
+            Job::Bst => "獣使い",
+            Job::Brd => "吟遊詩人",
+            Job::Rng => "狩人",
+            Job::Sam => "侍",
+            Job::Nin => "忍者",
+            Job::Drg => "竜騎士",
+            Job::Smn => "召喚士",
+            Job::Blu => "青魔道士",
+            Job::Cor => "コルセア",
+            Job::Pup => "からくり士",
+            Job::Dnc => "踊り子",
+            Job::Sch => "学者",
+            Job::Geo => "風水士",
+            Job::Run => "魔導剣士",
+        }
+    }
+
     pub fn status_grade(&self, kind: StatusKind) -> Option<Grade> {
         JOB_STATUS_GRADES[*self][kind]
     }
+
+    /// サポートジョブとして寄与する際のグレード。メインより 1 段階下げる (A→B, …, F→G)。
+    /// G は下限として据え置き。グレードを持たない場合は None。
+    pub fn status_grade_as_support(&self, kind: StatusKind) -> Option<Grade> {
+        self.status_grade(kind).map(|grade| grade.down())
+    }
+
+    /// グレードの良い順に上位 n 件のステータス。同グレードは StatusKind 定義順。
+    /// グレードを持たないステータス (MP なしジョブの MP) は含まない。
+    pub fn top_stats(&self, n: usize) -> Vec<StatusKind> {
+        let mut graded: Vec<(StatusKind, Grade)> = StatusKind::VARIANTS
+            .iter()
+            .filter_map(|&kind| self.status_grade(kind).map(|grade| (kind, grade)))
+            .collect();
+        // sort_by_key は安定ソートなので同グレードは定義順のまま
+        graded.sort_by_key(|&(_, grade)| grade as usize);
+        graded.into_iter().take(n).map(|(kind, _)| kind).collect()
+    }
+
+    /// このジョブ (メイン単独) が武器スキルを持つ武器種 (WeaponType 定義順)
+    pub fn usable_weapon_types(&self) -> Vec<WeaponType> {
+        WeaponType::VARIANTS
+            .iter()
+            .copied()
+            .filter(|weapon| job_skill_rank(*self, weapon.skill()).is_some())
+            .collect()
+    }
+
+    /// メインジョブとしてのスキル上限 (マスターレベル 0)。未習得のスキルは 0。
+    /// ランク → レベル別上限の換算は `skills::skill_cap` (制御点の線形補間) を参照
+    pub fn skill_cap(&self, skill: SkillKind, lv: i32) -> i32 {
+        job_skill_cap(*self, skill, lv, 0)
+    }
+
+    /// ジョブグレード単独での `calc_status` 結果 (0.5 単位の端数は切り捨て)。
+    /// 種族の値と足し合わせても `Chara::status` とは一致しない場合がある (合算後に丸めるため)。
+    /// グレードを持たない場合 (MP なしジョブの MP) は None。lv 0 (未育成) は `Some(0)`。
+    pub fn status_value(&self, kind: StatusKind, lv: i32) -> Option<i32> {
+        self.status_grade(kind).map(|grade| calc_status(kind, grade, lv).floor() as i32)
+    }
+}
+
+/// 英語略称・英語名 (大文字小文字は区別しない)・日本語名から変換する
+impl FromStr for Job {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "war" | "warrior" | "戦士" => Ok(Job::War),
+            "mnk" | "monk" | "モンク" => Ok(Job::Mnk),
+            "whm" | "white mage" | "白魔道士" => Ok(Job::Whm),
+            "blm" | "black mage" | "黒魔道士" => Ok(Job::Blm),
+            "rdm" | "red mage" | "赤魔道士" => Ok(Job::Rdm),
+            "thf" | "thief" | "シーフ" => Ok(Job::Thf),
+            "pld" | "paladin" | "ナイト" => Ok(Job::Pld),
+            "drk" | "dark knight" | "暗黒騎士" => Ok(Job::Drk),
+            "bst" | "beastmaster" | "獣使い" => Ok(Job::Bst),
+            "brd" | "bard" | "吟遊詩人" => Ok(Job::Brd),
+            "rng" | "ranger" | "狩人" => Ok(Job::Rng),
+            "sam" | "samurai" | "侍" => Ok(Job::Sam),
+            "nin" | "ninja" | "忍者" => Ok(Job::Nin),
+            "drg" | "dragoon" | "竜騎士" => Ok(Job::Drg),
+            "smn" | "summoner" | "召喚士" => Ok(Job::Smn),
+            "blu" | "blue mage" | "青魔道士" => Ok(Job::Blu),
+            "cor" | "corsair" | "コルセア" => Ok(Job::Cor),
+            "pup" | "puppetmaster" | "からくり士" => Ok(Job::Pup),
+            "dnc" | "dancer" | "踊り子" => Ok(Job::Dnc),
+            "sch" | "scholar" | "学者" => Ok(Job::Sch),
+            "geo" | "geomancer" | "風水士" => Ok(Job::Geo),
+            "run" | "rune fencer" | "魔導剣士" => Ok(Job::Run),
+            _ => Err(format!("Unknown job: {}", s)),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -61,7 +200,7 @@ impl Job {
 //     効果値 (%, 段階値) は別タスクで個別に実装する。
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VariantArray)]
 pub enum JobTrait {
     // wiki ジョブ特性一覧 (https://wiki.ffo.jp/html/450.html) の表示順に合わせる
     AttackBonus,
@@ -401,6 +540,7 @@ const MAX_DAMAGE_BOOST: &[i32] = &[10, 20, 30, 40, 50];
 /// プレースホルダー: 新規スケルトン特性用 (効果値未調査)。
 /// 8 ランクまでの (job, trait) に対応するため十分な長さを確保。
 /// 個別の特性実装時にこの参照を専用定数に差し替える。
+#[allow(dead_code)]
 const PLACEHOLDER_TRAIT: &[i32] = &[0; 10];
 
 impl JobTrait {
@@ -786,8 +926,26 @@ impl Job {
         let rank = self.trait_rank_at_lv(trait_kind, lv);
         trait_kind.value_at_rank(rank)
     }
+
+    /// このジョブ・指定 lv 時点で、能力値を直接上げるジョブ特性 (`STAT_TRAITS`) による
+    /// ステータスごとの加算値 (ギフト未考慮)。
+    pub fn trait_stat_bonus(&self, lv: i32) -> EnumMap<StatusKind, i32> {
+        let mut bonus = EnumMap::default();
+        for &(trait_kind, kind) in STAT_TRAITS {
+            bonus[kind] += self.trait_bonus(trait_kind, lv);
+        }
+        bonus
+    }
 }
 
+/// 能力値を直接上げるジョブ特性と、その対象ステータス。
+/// STR などの基本ステータスを直接上げるジョブ特性はゲームに存在しないため、HP/MP のみ。
+pub const STAT_TRAITS: &[(JobTrait, StatusKind)] = &[
+    (JobTrait::MaxHpBoost, StatusKind::Hp),
+    (JobTrait::MaxHpBoost2, StatusKind::Hp),
+    (JobTrait::MaxMpBoost, StatusKind::Mp),
+];
+
 impl JobTrait {
     /// 指定 rank に対応する累積効果値 (rank=0 → 0)。
     /// rank が cumulative 配列長を超える場合は配列末尾の値で clamp。
@@ -821,6 +979,149 @@ impl JobTrait {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_status_value() {
+        // War(B) Lv99: HP 675 / STR(A) 45
+        assert_eq!(Job::War.status_value(StatusKind::Hp, 99), Some(675));
+        assert_eq!(Job::War.status_value(StatusKind::Str, 99), Some(45));
+        // War は MP グレードを持たない
+        assert_eq!(Job::War.status_value(StatusKind::Mp, 99), None);
+        assert_eq!(Job::Blm.status_value(StatusKind::Mp, 99), Some(675));
+    }
+
+    #[test]
+    fn test_status_value_lv0() {
+        for &job in Job::VARIANTS {
+            for &kind in StatusKind::VARIANTS {
+                let expected = job.status_grade(kind).map(|_| 0);
+                assert_eq!(job.status_value(kind, 0), expected, "{:?} {:?}", job, kind);
+            }
+        }
+    }
+
+    #[test]
+    fn test_status_grade_as_support() {
+        // A → B
+        assert_eq!(Job::War.status_grade(StatusKind::Str), Some(Grade::A));
+        assert_eq!(
+            Job::War.status_grade_as_support(StatusKind::Str),
+            Some(Grade::B)
+        );
+        // F → G
+        assert_eq!(Job::War.status_grade(StatusKind::Int), Some(Grade::F));
+        assert_eq!(
+            Job::War.status_grade_as_support(StatusKind::Int),
+            Some(Grade::G)
+        );
+        // G は据え置き
+        assert_eq!(Job::Nin.status_grade(StatusKind::Mnd), Some(Grade::G));
+        assert_eq!(
+            Job::Nin.status_grade_as_support(StatusKind::Mnd),
+            Some(Grade::G)
+        );
+        // グレードなしは None のまま
+        assert_eq!(Job::War.status_grade_as_support(StatusKind::Mp), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("war".parse::<Job>(), Ok(Job::War));
+        assert_eq!("Black Mage".parse::<Job>(), Ok(Job::Blm));
+        assert_eq!("RUN".parse::<Job>(), Ok(Job::Run));
+        assert_eq!("赤魔道士".parse::<Job>(), Ok(Job::Rdm));
+        assert!("freelancer".parse::<Job>().is_err());
+    }
+
+    #[test]
+    fn test_name_ja_roundtrip() {
+        let mut names: Vec<&str> = Job::VARIANTS.iter().map(|job| job.name_ja()).collect();
+        for (job, name) in Job::VARIANTS.iter().zip(&names) {
+            assert!(!name.is_empty());
+            assert_eq!(name.parse::<Job>(), Ok(*job));
+        }
+        // 日本語名は 22 ジョブで重複しない
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), 22);
+    }
+
+    #[test]
+    fn test_trait_stat_bonus() {
+        // War の最大HPアップは Lv30 で習得
+        assert_eq!(Job::War.trait_stat_bonus(29)[StatusKind::Hp], 0);
+        assert_eq!(Job::War.trait_stat_bonus(30)[StatusKind::Hp], 30);
+        assert_eq!(Job::War.trait_stat_bonus(99)[StatusKind::Hp], 180);
+        // Mnk は最大HPアップ + 最大HPアップII の合計
+        assert_eq!(Job::Mnk.trait_stat_bonus(99)[StatusKind::Hp], 280 + 450);
+        assert_eq!(Job::Smn.trait_stat_bonus(99)[StatusKind::Mp], 100);
+        // 能力値系の特性を持たないステータスは 0
+        assert!(
+            StatusKind::VARIANTS
+                .iter()
+                .filter(|&&kind| !matches!(kind, StatusKind::Hp | StatusKind::Mp))
+                .all(|&kind| Job::War.trait_stat_bonus(99)[kind] == 0)
+        );
+    }
+
+    #[test]
+    fn test_usable_weapon_types() {
+        let war = Job::War.usable_weapon_types();
+        let mnk = Job::Mnk.usable_weapon_types();
+        assert_ne!(war, mnk);
+        assert!(war.contains(&WeaponType::GreatAxe));
+        assert!(!mnk.contains(&WeaponType::GreatAxe));
+        assert!(mnk.contains(&WeaponType::HandToHand));
+        assert!(war.len() > mnk.len());
+
+        let blm = Job::Blm.usable_weapon_types();
+        assert!(blm.contains(&WeaponType::Staff));
+        assert!(blm.contains(&WeaponType::Dagger));
+        assert!(!blm.contains(&WeaponType::Sword));
+
+        // 全ジョブが何らかの武器を扱える
+        assert!(
+            Job::VARIANTS
+                .iter()
+                .all(|job| !job.usable_weapon_types().is_empty())
+        );
+    }
+
+    #[test]
+    fn test_skill_cap() {
+        // War 両手斧 (A+) / Blm 元素魔法 (A+) の Lv99 上限
+        assert_eq!(Job::War.skill_cap(SkillKind::GreatAxe, 99), 424);
+        assert_eq!(Job::Blm.skill_cap(SkillKind::Elemental, 99), 424);
+        assert_eq!(Job::Blm.skill_cap(SkillKind::Elemental, 1), 6);
+        // 未習得スキルは 0
+        assert_eq!(Job::Blm.skill_cap(SkillKind::GreatAxe, 99), 0);
+        assert_eq!(Job::War.skill_cap(SkillKind::Elemental, 99), 0);
+    }
+
+    #[test]
+    fn test_top_stats() {
+        // War: STR A, HP B, DEX/AGI C (定義順で DEX が先)
+        assert_eq!(
+            Job::War.top_stats(3),
+            vec![StatusKind::Str, StatusKind::Hp, StatusKind::Dex]
+        );
+        // MP なしジョブは最大 8 件、n が多くても溢れない
+        assert_eq!(Job::War.top_stats(100).len(), 8);
+        assert!(!Job::War.top_stats(100).contains(&StatusKind::Mp));
+        assert_eq!(Job::Blm.top_stats(100).len(), 9);
+        assert!(Job::Blm.top_stats(0).is_empty());
+    }
+
+    #[test]
+    fn test_category() {
+        assert_eq!(Job::War.category(), JobCategory::Melee);
+        assert_eq!(Job::Blm.category(), JobCategory::Magic);
+        assert_eq!(Job::Cor.category(), JobCategory::Ranged);
+        // 全カテゴリに 1 ジョブ以上属する
+        for category in JobCategory::VARIANTS {
+            assert!(Job::VARIANTS.iter().any(|job| job.category() == *category));
+        }
+    }
+
     /// 連携ボーナス (Skillchain Bonus) ジョブ特性の値検証
     /// データソース: https://wiki.ffo.jp/html/20337.html
     /// 累積値: rank1=8, rank2=12, rank3=16, rank4=20, rank5=23
@@ -1,5 +1,6 @@
-use enum_map::EnumMap;
+use enum_map::{Enum, EnumMap};
 use serde::{Deserialize, Serialize};
+use strum::VariantArray;
 
 use crate::job::Job;
 
@@ -31,9 +32,34 @@ pub enum GiftStatKind {
     None,
 }
 
+/// ジョブポイントのカテゴリ枠 (ジョブポイントメニューの上から順、0 始まり)。
+/// 枠が何の効果を持つかはジョブごとに異なる (`jp_category_effects` を参照)。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum, VariantArray, Serialize, Deserialize)]
+pub enum JobPointCategory {
+    Category0,
+    Category1,
+    Category2,
+    Category3,
+    Category4,
+    Category5,
+    Category6,
+    Category7,
+    Category8,
+    Category9,
+}
+
+const _: () = assert!(JobPointCategory::VARIANTS.len() == JP_CATEGORY_COUNT);
+
+impl JobPointCategory {
+    /// `JobPointCategories::ranks` の添字
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 /// 1 ジョブ分のジョブポイントカテゴリランク情報。
 /// 各カテゴリは 0..=20 のランクを持ち、ランク r まで振るために必要な JP は r*(r+1)/2。
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct JobPointCategories {
     pub ranks: [i32; JP_CATEGORY_COUNT],
 }
@@ -54,6 +80,24 @@ impl JobPointCategories {
         }
     }
 
+    pub fn rank(&self, category: JobPointCategory) -> i32 {
+        self.ranks[category.index()]
+    }
+
+    /// カテゴリ別の振り分けを `EnumMap` で返す
+    pub fn allocation(&self) -> EnumMap<JobPointCategory, i32> {
+        EnumMap::from_fn(|category| self.rank(category))
+    }
+
+    /// カテゴリ別の振り分けから生成する
+    pub fn from_allocation(allocation: EnumMap<JobPointCategory, i32>) -> Self {
+        let mut categories = Self::default();
+        for (category, rank) in allocation {
+            categories.ranks[category.index()] = rank;
+        }
+        categories
+    }
+
     /// このジョブに投入した累計 JP の合計を返す。
     /// ランク r のコスト = r*(r+1)/2 (1+2+...+r)
     pub fn total_jp_spent(&self) -> i32 {
@@ -61,7 +105,7 @@ impl JobPointCategories {
             .iter()
             .map(|&r| {
                 assert!(
-                    r >= 0 && r <= JP_MAX_RANK,
+                    (0..=JP_MAX_RANK).contains(&r),
                     "JP category rank must be between 0 and {}: {}",
                     JP_MAX_RANK,
                     r
@@ -243,6 +287,17 @@ mod tests {
         assert_eq!(cats.total_jp_spent(), 1 + 15 + 55);
     }
 
+    #[test]
+    fn test_allocation_roundtrip() {
+        let mut allocation = EnumMap::default();
+        allocation[JobPointCategory::Category3] = 20;
+        allocation[JobPointCategory::Category9] = 5;
+        let cats = JobPointCategories::from_allocation(allocation);
+        assert_eq!(cats.ranks, [0, 0, 0, 20, 0, 0, 0, 0, 0, 5]);
+        assert_eq!(cats.rank(JobPointCategory::Category3), 20);
+        assert_eq!(cats.allocation(), allocation);
+    }
+
     #[test]
     fn test_gift_bonuses_war_maxed() {
         // War at 2100 JP: 各スロットの 4 ティアがすべて解放され累積加算される
@@ -1,9 +1,19 @@
+pub mod analysis;
 pub mod chara;
 pub mod character_profile;
+pub mod cli;
+pub mod combat;
 pub mod data_loader;
+pub mod error;
+pub mod formula;
 pub mod gift;
 pub mod job;
 pub mod job_points;
+pub mod merit;
+pub mod modifier;
+pub mod party;
+pub mod precomputed;
+pub mod prelude;
 pub mod race;
 pub mod skills;
 pub mod status;
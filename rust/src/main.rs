@@ -1,3 +1,13 @@
+use clap::Parser;
+use ff11sim::cli::{Cli, run};
+
 fn main() {
-    println!("Hello, world!");
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(out) => print!("{}", out),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+
+use crate::status::{EnumCount, StatusKind, VariantArray};
+
+/// メリットポイントのカテゴリ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, VariantArray, Serialize, Deserialize)]
+pub enum MeritCategory {
+    /// HP/MP・基本ステータス
+    StatusBonus,
+    /// 戦闘スキル
+    Combat,
+    /// 魔法スキル
+    Magic,
+    /// 敵対心・クリティカルヒット率など
+    Other,
+}
+
+/// メリット項目の上限段数と 1 段あたりの効果量
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeritSpec {
+    pub category: MeritCategory,
+    pub max_rank: i32,
+    pub per_rank: i32,
+}
+
+impl MeritSpec {
+    const fn new(category: MeritCategory, max_rank: i32, per_rank: i32) -> Self {
+        Self {
+            category,
+            max_rank,
+            per_rank,
+        }
+    }
+
+    /// rank が 0〜`max_rank` の範囲内か
+    pub fn contains(&self, rank: i32) -> bool {
+        (0..=self.max_rank).contains(&rank)
+    }
+
+    /// rank 段振り分けたときの効果量
+    pub fn bonus(&self, rank: i32) -> i32 {
+        self.per_rank * rank
+    }
+}
+
+// ステータス系メリット (StatusKind の並び順)。
+// HP/MP は段が上がるごとに必要なメリットポイントが増える (逓増) が、加算量は実機でも各段 +10 で一定。
+pub const STATUS_MERITS: [MeritSpec; StatusKind::COUNT] = [
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 10), // HP
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 10), // MP
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // STR
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // DEX
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // VIT
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // AGI
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // INT
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // MND
+    MeritSpec::new(MeritCategory::StatusBonus, 15, 1),  // CHR
+];
+
+/// 戦闘スキルメリット (スキルキャップ +2/rank)
+pub const COMBAT_SKILL_MERIT: MeritSpec = MeritSpec::new(MeritCategory::Combat, 8, 2);
+
+/// 魔法スキルメリット (スキルキャップ +2/rank)
+pub const MAGIC_SKILL_MERIT: MeritSpec = MeritSpec::new(MeritCategory::Magic, 8, 2);
+
+/// ステータス系メリットの定義
+pub fn status_merit(kind: StatusKind) -> MeritSpec {
+    STATUS_MERITS[kind as usize]
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MeritPoints {
+    pub hp: i32,
+    pub mp: i32,
+    pub str_: i32,
+    pub dex: i32,
+    pub vit: i32,
+    pub agi: i32,
+    pub int: i32,
+    pub mnd: i32,
+    pub chr: i32,
+    /// 戦闘スキルメリット (キー: スキル名, 値: 0-8, +2/rank)
+    #[serde(default)]
+    pub combat_skill_merits: std::collections::BTreeMap<String, i32>,
+    /// 魔法スキルメリット (キー: スキル名, 値: 0-8, +2/rank)
+    #[serde(default)]
+    pub magic_skill_merits: std::collections::BTreeMap<String, i32>,
+    /// 敵対心+ (0-5, +1/rank)
+    #[serde(default)]
+    pub enmity_plus: i32,
+    /// 敵対心- (0-5, -1/rank)
+    #[serde(default)]
+    pub enmity_minus: i32,
+    /// クリティカルヒット率 (0-5, +1%/rank)
+    #[serde(default)]
+    pub critical_hit_rate: i32,
+    /// 被クリティカルヒット率 (0-5, -1%/rank)
+    #[serde(default)]
+    pub enemy_critical_hit_rate: i32,
+    /// 詠唱中断率 (0-5, -2%/rank)
+    #[serde(default)]
+    pub spell_interruption_rate: i32,
+    /// Store TP メリット (SAM 専用, 0-5, +1/rank)
+    #[serde(default)]
+    pub store_tp: i32,
+    /// ジョブ別メリットポイント (Group 1 + Group 2、各 8 カテゴリ × 0-5 rank)。
+    /// 現状ステータス計算には未反映（カテゴリ名・効果は別途扱い）。
+    /// キー: ジョブ短縮名（"War", "Sam", ...）
+    #[serde(default)]
+    pub job_merits: std::collections::BTreeMap<String, JobMerits>,
+}
+
+/// 単一ジョブのメリットポイント（Group 1 / Group 2、各 8 カテゴリ）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct JobMerits {
+    #[serde(default)]
+    pub group1: [i32; 8],
+    #[serde(default)]
+    pub group2: [i32; 8],
+}
+
+impl MeritPoints {
+    pub fn get(&self, kind: StatusKind) -> i32 {
+        match kind {
+            StatusKind::Hp => self.hp,
+            StatusKind::Mp => self.mp,
+            StatusKind::Str => self.str_,
+            StatusKind::Dex => self.dex,
+            StatusKind::Vit => self.vit,
+            StatusKind::Agi => self.agi,
+            StatusKind::Int => self.int,
+            StatusKind::Mnd => self.mnd,
+            StatusKind::Chr => self.chr,
+        }
+    }
+
+    /// HP/MP・基本ステータスのメリットランクがすべて範囲内 (`STATUS_MERITS` の上限) か確認する
+    pub fn validate(&self) -> Result<(), String> {
+        for &kind in StatusKind::VARIANTS {
+            let rank = self.get(kind);
+            let spec = status_merit(kind);
+            if !spec.contains(rank) {
+                return Err(format!(
+                    "{:?} merit point rank must be between 0 and {}: {}",
+                    kind, spec.max_rank, rank
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn status_bonus(&self, kind: StatusKind) -> i32 {
+        let rank = self.get(kind);
+        let spec = status_merit(kind);
+        assert!(
+            spec.contains(rank),
+            "merit point rank must be between 0 and 15"
+        );
+        spec.bonus(rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merit_hp_mp_steps() {
+        for kind in [StatusKind::Hp, StatusKind::Mp] {
+            for (rank, expected) in [(0, 0), (1, 10), (2, 20), (5, 50), (10, 100), (15, 150)] {
+                let mut merit = MeritPoints::default();
+                match kind {
+                    StatusKind::Hp => merit.hp = rank,
+                    _ => merit.mp = rank,
+                }
+                assert_eq!(
+                    merit.status_bonus(kind),
+                    expected,
+                    "{:?} rank {}",
+                    kind,
+                    rank
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_merit_bp_linear() {
+        let merit = MeritPoints {
+            str_: 3,
+            chr: 15,
+            ..Default::default()
+        };
+        assert_eq!(merit.status_bonus(StatusKind::Str), 3);
+        assert_eq!(merit.status_bonus(StatusKind::Chr), 15);
+        assert_eq!(merit.status_bonus(StatusKind::Dex), 0);
+    }
+
+    #[test]
+    fn test_status_merits_are_status_bonus() {
+        for &kind in StatusKind::VARIANTS {
+            let spec = status_merit(kind);
+            assert_eq!(spec.category, MeritCategory::StatusBonus);
+            assert_eq!(spec.max_rank, 15);
+        }
+        assert_eq!(COMBAT_SKILL_MERIT.category, MeritCategory::Combat);
+        assert_eq!(MAGIC_SKILL_MERIT.bonus(8), 16);
+    }
+
+    #[test]
+    fn test_validate_uses_max_rank() {
+        let mut merit = MeritPoints {
+            hp: 15,
+            ..Default::default()
+        };
+        assert!(merit.validate().is_ok());
+        merit.hp = 16;
+        assert_eq!(
+            merit.validate().unwrap_err(),
+            "Hp merit point rank must be between 0 and 15: 16"
+        );
+    }
+}
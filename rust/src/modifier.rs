@@ -0,0 +1,137 @@
+use crate::chara::Chara;
+use crate::status::{BonusStats, Status, StatusKind, VariantArray};
+
+/// 装備・食事・バフなど、計算済みのステータスに差分を適用するもの
+pub trait Modifier {
+    fn modify(&self, status: &mut Status);
+}
+
+/// 装備のステータス加算 (`status()` と同じく i32 の範囲で飽和する)
+impl Modifier for BonusStats {
+    fn modify(&self, status: &mut Status) {
+        for &kind in StatusKind::VARIANTS {
+            status[kind] = status[kind].saturating_add(self.get(kind));
+        }
+    }
+}
+
+/// 食事などの割合加算: 対象ステータスの `percent`% (端数切り捨て、上限 `cap`) を加算する。
+/// 途中の乗算・加算は i32 の範囲で飽和する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentModifier {
+    pub kind: StatusKind,
+    pub percent: i32,
+    pub cap: i32,
+}
+
+impl Modifier for PercentModifier {
+    fn modify(&self, status: &mut Status) {
+        let bonus = (status[self.kind].saturating_mul(self.percent) / 100).min(self.cap);
+        status[self.kind] = status[self.kind].saturating_add(bonus);
+    }
+}
+
+impl Chara {
+    /// `m` を適用した Chara を返す (self は変更しない)。
+    /// 適用前後のステータスの差分を `bonus_stats` に加算するので、返り値の `to_status()` は
+    /// `self.to_status()` に `m.modify` を適用した値と一致する (ただし 0 未満は 0 に丸められる)。
+    pub fn apply(&self, m: &dyn Modifier) -> Chara {
+        let before = self.to_status();
+        let mut after = before.clone();
+        m.modify(&mut after);
+
+        let mut chara = self.clone();
+        for &kind in StatusKind::VARIANTS {
+            let bonus = chara.bonus_stats.get_mut(kind);
+            *bonus = bonus.saturating_add(after[kind].saturating_sub(before[kind]));
+        }
+        chara
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::job::Job;
+    use crate::race::Race;
+
+    fn war_drg() -> Chara {
+        Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Drg, 59)
+            .master_lv(50)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_apply_bonus_stats() {
+        let gear = BonusStats {
+            hp: 300,
+            str_: 40,
+            ..BonusStats::default()
+        };
+        let chara = war_drg();
+        let geared = chara.apply(&gear);
+        assert_eq!(geared.status(StatusKind::Hp), 1915 + 300);
+        assert_eq!(geared.status(StatusKind::Str), 146 + 40);
+        // 元の Chara は変更されない
+        assert_eq!(chara.status(StatusKind::Hp), 1915);
+    }
+
+    #[test]
+    fn test_apply_in_order_matches_composed_modify() {
+        let gear = BonusStats {
+            hp: 300,
+            vit: 20,
+            ..BonusStats::default()
+        };
+        // 食事: HP+10% (上限 200)
+        let food = PercentModifier {
+            kind: StatusKind::Hp,
+            percent: 10,
+            cap: 200,
+        };
+        let chara = war_drg();
+
+        let applied = chara.apply(&gear).apply(&food);
+        let mut expected = chara.to_status();
+        gear.modify(&mut expected);
+        food.modify(&mut expected);
+        assert_eq!(applied.to_status(), expected);
+        // HP: 1915 + 300 = 2215 → +221 (10%) は上限 200 で打ち止め
+        assert_eq!(applied.status(StatusKind::Hp), 2415);
+
+        // 順序を入れ替えると割合加算の対象が変わる
+        let reversed = chara.apply(&food).apply(&gear);
+        assert_eq!(reversed.status(StatusKind::Hp), 1915 + 191 + 300);
+    }
+
+    #[test]
+    fn test_modify_saturates() {
+        let gear = BonusStats {
+            hp: i32::MAX,
+            str_: i32::MIN,
+            ..BonusStats::default()
+        };
+        let food = PercentModifier {
+            kind: StatusKind::Hp,
+            percent: i32::MAX,
+            cap: i32::MAX,
+        };
+        let chara = war_drg();
+
+        let mut status = chara.to_status();
+        gear.modify(&mut status);
+        assert_eq!(status[StatusKind::Hp], i32::MAX);
+        assert_eq!(status[StatusKind::Str], 146 + i32::MIN);
+        food.modify(&mut status);
+        assert_eq!(status[StatusKind::Hp], i32::MAX);
+
+        // apply も同じく飽和し、0 未満は 0 に丸められる
+        let applied = chara.apply(&gear).apply(&food);
+        assert_eq!(applied.status(StatusKind::Hp), i32::MAX);
+        assert_eq!(applied.status(StatusKind::Str), 0);
+    }
+}
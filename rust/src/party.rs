@@ -0,0 +1,114 @@
+use crate::chara::Chara;
+use crate::status::StatusKind;
+
+/// パーティ全体に共有されるバフ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartyBuff {
+    /// 全メンバーの対象ステータスに一律で加算する
+    Flat { kind: StatusKind, value: i32 },
+}
+
+impl PartyBuff {
+    /// このバフによる指定メンバー・ステータスへの加算値
+    pub fn bonus(&self, chara: &Chara, kind: StatusKind) -> i32 {
+        match *self {
+            PartyBuff::Flat {
+                kind: target,
+                value,
+            } => {
+                // MP を持たないメインジョブの MP は 0 のまま (Chara::status と同じ扱い)
+                if target != kind
+                    || (kind == StatusKind::Mp && chara.main_job.status_grade(kind).is_none())
+                {
+                    0
+                } else {
+                    value
+                }
+            }
+        }
+    }
+}
+
+/// パーティバフ適用後のメンバー個別のステータス
+pub fn buffed_status(chara: &Chara, buffs: &[PartyBuff], kind: StatusKind) -> i32 {
+    chara.status(kind) + buffs.iter().map(|b| b.bonus(chara, kind)).sum::<i32>()
+}
+
+/// パーティバフ適用後のメンバー全員のステータス合計
+pub fn party_total(members: &[Chara], buffs: &[PartyBuff], kind: StatusKind) -> i32 {
+    members
+        .iter()
+        .map(|chara| buffed_status(chara, buffs, kind))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::job::Job;
+    use crate::race::Race;
+
+    fn party() -> Vec<Chara> {
+        [
+            (Race::Hum, Job::War),
+            (Race::Tar, Job::Blm),
+            (Race::Gal, Job::Pld),
+        ]
+        .into_iter()
+        .map(|(race, job)| {
+            Chara::builder()
+                .race(race)
+                .main_job(job, 99)
+                .master_lv(0)
+                .build()
+                .unwrap()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_party_total_without_buff() {
+        let members = party();
+        let expected: i32 = members.iter().map(|c| c.status(StatusKind::Str)).sum();
+        assert_eq!(party_total(&members, &[], StatusKind::Str), expected);
+    }
+
+    #[test]
+    fn test_party_flat_buff_applies_to_all_members() {
+        let members = party();
+        let buffs = [PartyBuff::Flat {
+            kind: StatusKind::Vit,
+            value: 20,
+        }];
+        for chara in &members {
+            assert_eq!(
+                buffed_status(chara, &buffs, StatusKind::Vit),
+                chara.status(StatusKind::Vit) + 20
+            );
+            // 対象外ステータスは変化しない
+            assert_eq!(
+                buffed_status(chara, &buffs, StatusKind::Str),
+                chara.status(StatusKind::Str)
+            );
+        }
+        assert_eq!(
+            party_total(&members, &buffs, StatusKind::Vit),
+            party_total(&members, &[], StatusKind::Vit) + 20 * 3
+        );
+    }
+
+    #[test]
+    fn test_party_mp_buff_skips_jobs_without_mp() {
+        let members = party();
+        let buffs = [PartyBuff::Flat {
+            kind: StatusKind::Mp,
+            value: 50,
+        }];
+        // War は MP を持たないので 0 のまま、Blm/Pld のみ +50
+        assert_eq!(buffed_status(&members[0], &buffs, StatusKind::Mp), 0);
+        assert_eq!(
+            party_total(&members, &buffs, StatusKind::Mp),
+            party_total(&members, &[], StatusKind::Mp) + 50 * 2
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! build.rs で事前計算した「種族 + メインジョブ」ステータスのテーブル。
+//! 実行時はグレード係数からの計算を行わず lookup のみで済む (WASM の初回計算コスト削減)。
+
+use std::sync::LazyLock;
+
+use enum_map::EnumMap;
+
+use crate::job::Job;
+use crate::race::Race;
+use crate::status::{EnumCount, Grade, StatusKind};
+
+/// 事前計算の対象レベル (1..=MAX_LV)
+const MAX_LV: usize = 99;
+
+/// (種族, ジョブ, Lv, ステータス) の順に並んだ 0.5 単位の u16 (little endian)
+static PRECOMPUTED_STATUS: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/precomputed_status.bin"));
+
+const _: () =
+    assert!(PRECOMPUTED_STATUS.len() == Race::COUNT * Job::COUNT * MAX_LV * StatusKind::COUNT * 2);
+
+/// 種族とメインジョブのグレードによるステータス (切り捨て前)。
+/// `calc_status(種族グレード) + calc_status(ジョブグレード)` と一致する (ジョブが MP を持たない場合は種族分のみ)。
+/// 事前計算の範囲外 (Lv1-99 以外) は None。
+pub fn race_job_status(race: Race, job: Job, kind: StatusKind, lv: i32) -> Option<f32> {
+    if !(1..=MAX_LV as i32).contains(&lv) {
+        return None;
+    }
+    let index = ((race as usize * Job::COUNT + job as usize) * MAX_LV + (lv as usize - 1))
+        * StatusKind::COUNT
+        + kind as usize;
+    let halves = u16::from_le_bytes([
+        PRECOMPUTED_STATUS[index * 2],
+        PRECOMPUTED_STATUS[index * 2 + 1],
+    ]);
+    Some(halves as f32 / 2.0)
+}
+
+/// 種族 × ジョブ × ステータスごとの (種族グレード, メインジョブグレード)
+type RaceJobGrades = EnumMap<Race, EnumMap<Job, EnumMap<StatusKind, (Grade, Option<Grade>)>>>;
+
+/// 初回アクセス時に構築するグレードの組のテーブル
+static RACE_JOB_GRADES: LazyLock<RaceJobGrades> = LazyLock::new(|| {
+    EnumMap::from_fn(|race: Race| {
+        EnumMap::from_fn(|job: Job| {
+            EnumMap::from_fn(|kind| (race.status_grade(kind), job.status_grade(kind)))
+        })
+    })
+});
+
+/// 種族とメインジョブのグレードの組 (ジョブがグレードを持たない場合は None)
+pub fn race_job_grades(race: Race, job: Job, kind: StatusKind) -> (Grade, Option<Grade>) {
+    RACE_JOB_GRADES[race][job][kind]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::{calc_status, VariantArray};
+
+    #[test]
+    fn test_race_job_status_matches_calc_status() {
+        for &race in Race::VARIANTS {
+            for &job in Job::VARIANTS {
+                for &kind in StatusKind::VARIANTS {
+                    for lv in 1..=99 {
+                        let expected = calc_status(kind, race.status_grade(kind), lv)
+                            + job
+                                .status_grade(kind)
+                                .map(|grade| calc_status(kind, grade, lv))
+                                .unwrap_or(0.0);
+                        assert_eq!(
+                            race_job_status(race, job, kind, lv),
+                            Some(expected),
+                            "{:?} {:?} {:?} Lv{}",
+                            race,
+                            job,
+                            kind,
+                            lv
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_race_job_grades() {
+        for &race in Race::VARIANTS {
+            for &job in Job::VARIANTS {
+                for &kind in StatusKind::VARIANTS {
+                    assert_eq!(
+                        race_job_grades(race, job, kind),
+                        (race.status_grade(kind), job.status_grade(kind))
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            race_job_grades(Race::Hum, Job::War, StatusKind::Mp),
+            (Grade::D, None)
+        );
+    }
+
+    #[test]
+    fn test_race_job_status_out_of_range() {
+        assert_eq!(
+            race_job_status(Race::Hum, Job::War, StatusKind::Hp, 0),
+            None
+        );
+        assert_eq!(
+            race_job_status(Race::Hum, Job::War, StatusKind::Hp, 100),
+            None
+        );
+        // Hum War99: HP 485 + 675, STR 37.5 + 45
+        assert_eq!(
+            race_job_status(Race::Hum, Job::War, StatusKind::Hp, 99),
+            Some(1160.0)
+        );
+        assert_eq!(
+            race_job_status(Race::Hum, Job::War, StatusKind::Str, 99),
+            Some(82.5)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! よく使う型と trait をまとめた re-export。
+//!
+//! ```
+//! use ff11sim::prelude::*;
+//!
+//! let chara = Chara::builder()
+//!     .race(Race::Hum)
+//!     .main_job(Job::War, 99)
+//!     .support_job(Job::Drg, 59)
+//!     .master_lv(50)
+//!     .merit_points(MeritPoints::default())
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(chara.status(StatusKind::Hp), 1915);
+//!
+//! let mut profile = CharacterProfile::new("Adventurer".to_string(), Race::Hum);
+//! profile.set_job_level(Job::War, 99, 50);
+//! profile.set_job_level(Job::Drg, 59, 0);
+//! let mut registry = CharaRegistry::new();
+//! registry.register(profile).unwrap();
+//! let chara = registry.to_chara("Adventurer", Job::War, Some(Job::Drg)).unwrap();
+//! assert_eq!(chara.to_status().str, 146);
+//!
+//! // strum の trait も使える
+//! assert_eq!(StatusKind::VARIANTS.len(), StatusKind::COUNT);
+//! ```
+
+pub use crate::chara::{BuildError, Chara, CharaBuilder, SupportHpMpModel, SupportLevelRounding};
+pub use crate::character_profile::{
+    CharaRegistry, CharacterProfile, JobLevel, MergeStrategy, ProfileError,
+};
+pub use crate::error::{ApiError, ErrorCode};
+pub use crate::job::{Job, JobCategory, JobTrait};
+pub use crate::job_points::{JobPointCategories, JobPointCategory, JobPoints};
+pub use crate::merit::{MeritCategory, MeritSpec};
+pub use crate::modifier::{Modifier, PercentModifier};
+pub use crate::race::Race;
+pub use crate::skills::{CharacterSkills, SkillKind, WeaponType};
+pub use crate::status::{
+    BonusStats, EnumCount, EnumIter, Grade, MeritPoints, Status, StatusKind, VariantArray,
+};
@@ -1,5 +1,7 @@
+use std::str::FromStr;
+
 use crate::data_loader::RACE_STATUS_GRADES;
-use crate::status::{Grade, StatusKind};
+use crate::status::{calc_status, Grade, StatusKind};
 
 use clap::ValueEnum;
 use enum_map::Enum;
@@ -19,6 +21,40 @@ impl Race {
     pub fn status_grade(&self, kind: StatusKind) -> Grade {
         RACE_STATUS_GRADES[*self][kind]
     }
+
+    /// 日本語の種族名
+    pub fn name_ja(&self) -> &'static str {
+        match self {
+            Race::Hum => "ヒューム",
+            Race::Elv => "エルヴァーン",
+            Race::Tar => "タルタル",
+            Race::Mit => "ミスラ",
+            Race::Gal => "ガルカ",
+        }
+    }
+
+    /// 種族グレード単独での `calc_status` 結果 (0.5 単位の端数は切り捨て)。
+    /// ジョブの値と足し合わせても `Chara::status` とは一致しない場合がある (合算後に丸めるため)。
+    /// lv 0 (未育成) は 0。
+    pub fn status_value(&self, kind: StatusKind, lv: i32) -> i32 {
+        calc_status(kind, self.status_grade(kind), lv).floor() as i32
+    }
+}
+
+/// 英語略称・英語名 (大文字小文字は区別しない)・日本語名から変換する
+impl FromStr for Race {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hum" | "hume" | "ヒューム" => Ok(Race::Hum),
+            "elv" | "elvaan" | "エルヴァーン" => Ok(Race::Elv),
+            "tar" | "tarutaru" | "タルタル" => Ok(Race::Tar),
+            "mit" | "mithra" | "ミスラ" => Ok(Race::Mit),
+            "gal" | "galka" | "ガルカ" => Ok(Race::Gal),
+            _ => Err(format!("Unknown race: {}", s)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -44,4 +80,41 @@ mod tests {
         assert_eq!(Race::Mit.status_grade(StatusKind::Dex), Grade::A);
         assert_eq!(Race::Gal.status_grade(StatusKind::Hp), Grade::A);
     }
+
+    #[test]
+    fn test_status_value() {
+        // Hum(D) Lv99: HP 485 / STR 37.5 → 37
+        assert_eq!(Race::Hum.status_value(StatusKind::Hp, 99), 485);
+        assert_eq!(Race::Hum.status_value(StatusKind::Str, 99), 37);
+        // Tar(A) Lv99: MP 736
+        assert_eq!(Race::Tar.status_value(StatusKind::Mp, 99), 736);
+    }
+
+    #[test]
+    fn test_status_value_lv0() {
+        for &race in Race::VARIANTS {
+            for &kind in StatusKind::VARIANTS {
+                assert_eq!(race.status_value(kind, 0), 0, "{:?} {:?}", race, kind);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("hum".parse::<Race>(), Ok(Race::Hum));
+        assert_eq!("Elvaan".parse::<Race>(), Ok(Race::Elv));
+        assert_eq!("TAR".parse::<Race>(), Ok(Race::Tar));
+        assert_eq!("ミスラ".parse::<Race>(), Ok(Race::Mit));
+        assert!("orc".parse::<Race>().is_err());
+    }
+
+    #[test]
+    fn test_name_ja_roundtrip() {
+        for race in Race::VARIANTS {
+            let name = race.name_ja();
+            let parsed: Race = name.parse().unwrap();
+            assert_eq!(parsed, *race);
+            assert_eq!(parsed.name_ja(), name);
+        }
+    }
 }
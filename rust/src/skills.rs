@@ -5,6 +5,7 @@ use strum::{EnumCount, EnumIter, VariantArray};
 use crate::character_profile::JobLevel;
 use crate::data_loader::{JOB_SKILL_RANKS, SKILL_CAP_CONTROL_POINTS};
 use crate::job::Job;
+use crate::merit::{COMBAT_SKILL_MERIT, MAGIC_SKILL_MERIT};
 
 // ---------------------------------------------------------------------------
 // SkillKind / SkillRank
@@ -127,12 +128,12 @@ impl SkillKind {
 
     /// メリットポイントによるスキルキャップボーナス (+2/rank)
     pub fn merit_bonus(self, merit: &crate::status::MeritPoints, skill_key: &str) -> i32 {
-        let rank = if self.is_combat() {
-            merit.combat_skill_merits.get(skill_key).copied().unwrap_or(0)
+        let (ranks, spec) = if self.is_combat() {
+            (&merit.combat_skill_merits, COMBAT_SKILL_MERIT)
         } else {
-            merit.magic_skill_merits.get(skill_key).copied().unwrap_or(0)
+            (&merit.magic_skill_merits, MAGIC_SKILL_MERIT)
         };
-        rank * 2
+        spec.bonus(ranks.get(skill_key).copied().unwrap_or(0))
     }
 }
 
@@ -245,25 +246,67 @@ pub fn weapon_skill_from_item_id(skill_id: i32) -> Option<SkillKind> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// WeaponType
+// ---------------------------------------------------------------------------
+
+/// 武器種。武器スキル 15 種と 1 対 1 に対応する。
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount, EnumIter, VariantArray, Enum, Serialize,
+    Deserialize,
+)]
+pub enum WeaponType {
+    HandToHand,
+    Dagger,
+    Sword,
+    GreatSword,
+    Axe,
+    GreatAxe,
+    Scythe,
+    Polearm,
+    Katana,
+    GreatKatana,
+    Club,
+    Staff,
+    Archery,
+    Marksmanship,
+    Throwing,
+}
+
+impl WeaponType {
+    /// 対応する武器スキル
+    pub fn skill(self) -> SkillKind {
+        match self {
+            WeaponType::HandToHand => SkillKind::HandToHand,
+            WeaponType::Dagger => SkillKind::Dagger,
+            WeaponType::Sword => SkillKind::Sword,
+            WeaponType::GreatSword => SkillKind::GreatSword,
+            WeaponType::Axe => SkillKind::Axe,
+            WeaponType::GreatAxe => SkillKind::GreatAxe,
+            WeaponType::Scythe => SkillKind::Scythe,
+            WeaponType::Polearm => SkillKind::Polearm,
+            WeaponType::Katana => SkillKind::Katana,
+            WeaponType::GreatKatana => SkillKind::GreatKatana,
+            WeaponType::Club => SkillKind::Club,
+            WeaponType::Staff => SkillKind::Staff,
+            WeaponType::Archery => SkillKind::Archery,
+            WeaponType::Marksmanship => SkillKind::Marksmanship,
+            WeaponType::Throwing => SkillKind::Throwing,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // CharacterSkills
 // ---------------------------------------------------------------------------
 
 /// キャラクターのスキル値。全ジョブで共通の 1 組を保持する。
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CharacterSkills {
     #[serde(default)]
     pub values: EnumMap<SkillKind, i32>,
 }
 
-impl Default for CharacterSkills {
-    fn default() -> Self {
-        Self {
-            values: EnumMap::default(),
-        }
-    }
-}
-
 impl CharacterSkills {
     pub fn get(&self, skill: SkillKind) -> i32 {
         self.values[skill]
@@ -316,6 +359,7 @@ pub fn default_skills(
 /// メイン/サポートジョブの組み合わせにおけるスキルの有効値を計算する。
 /// キャラクターのスキル値とジョブ経由のキャップの最大値のうち、低い方を返す。
 /// キャップはメインジョブ（+ ML）とサポートジョブ（support_lv で上限）のうち高い方 + メリットボーナス。
+#[allow(clippy::too_many_arguments)]
 pub fn effective_skill(
     skill: SkillKind,
     main_job: Job,
@@ -466,7 +510,7 @@ mod tests {
         // Nin の片手刀 A+ @ Lv49, 線形補間で 1-50 の中
         // APlus: [6, 153, 276, 424] at [1, 50, 75, 99]
         // Lv49: 6 + (49-1)/(50-1) * (153-6) = 6 + 48/49 * 147 ≈ 6 + 144 = 150
-        assert!(v >= 140 && v <= 160, "v = {}", v);
+        assert!((140..=160).contains(&v), "v = {}", v);
     }
 
     #[test]
@@ -1,10 +1,13 @@
-use core::panic;
+use std::ops::{Index, IndexMut};
 
-use enum_map::Enum;
+use enum_map::{Enum, EnumMap};
 use serde::{Deserialize, Serialize};
 pub use strum::{EnumCount, EnumIter, VariantArray};
 
-use crate::data_loader::GRADE_COEFFICIENTS;
+use crate::data_loader::{GradeCoefficients, GRADE_COEFFICIENTS};
+use crate::formula::{floor_half, levels_in_band, status_from_row, HPMP_30PLUS_START, LEVEL_BANDS};
+pub use crate::formula::{RoundingStrategy, MAX_GROWTH_LV};
+pub use crate::merit::{JobMerits, MeritPoints};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount, EnumIter, VariantArray, Enum, Serialize, Deserialize)]
 pub enum Grade {
@@ -17,6 +20,27 @@ pub enum Grade {
     G,
 }
 
+/// HP/MP。基本ステータスと係数テーブルが異なり、Lv31 以降の 30+ 項を持つ
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumCount,
+    EnumIter,
+    VariantArray,
+    Enum,
+    Serialize,
+    Deserialize,
+)]
+pub enum HpMpKind {
+    Hp,
+    Mp,
+}
+
+/// 基本ステータス (STR〜CHR)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount, EnumIter, VariantArray, Enum, Serialize, Deserialize)]
 pub enum BpKind {
     Str,
@@ -41,7 +65,54 @@ pub enum StatusKind {
     Chr,
 }
 
-#[derive(Debug, Clone)]
+/// `StatusKind` を計算系統 (HP/MP・基本ステータス) ごとに分けたもの
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusClass {
+    HpMp(HpMpKind),
+    Bp(BpKind),
+}
+
+impl StatusKind {
+    /// 計算系統での分類
+    pub fn class(self) -> StatusClass {
+        match self {
+            StatusKind::Hp => StatusClass::HpMp(HpMpKind::Hp),
+            StatusKind::Mp => StatusClass::HpMp(HpMpKind::Mp),
+            StatusKind::Str => StatusClass::Bp(BpKind::Str),
+            StatusKind::Dex => StatusClass::Bp(BpKind::Dex),
+            StatusKind::Vit => StatusClass::Bp(BpKind::Vit),
+            StatusKind::Agi => StatusClass::Bp(BpKind::Agi),
+            StatusKind::Int => StatusClass::Bp(BpKind::Int),
+            StatusKind::Mnd => StatusClass::Bp(BpKind::Mnd),
+            StatusKind::Chr => StatusClass::Bp(BpKind::Chr),
+        }
+    }
+}
+
+impl From<HpMpKind> for StatusKind {
+    fn from(kind: HpMpKind) -> Self {
+        match kind {
+            HpMpKind::Hp => StatusKind::Hp,
+            HpMpKind::Mp => StatusKind::Mp,
+        }
+    }
+}
+
+impl From<BpKind> for StatusKind {
+    fn from(kind: BpKind) -> Self {
+        match kind {
+            BpKind::Str => StatusKind::Str,
+            BpKind::Dex => StatusKind::Dex,
+            BpKind::Vit => StatusKind::Vit,
+            BpKind::Agi => StatusKind::Agi,
+            BpKind::Int => StatusKind::Int,
+            BpKind::Mnd => StatusKind::Mnd,
+            BpKind::Chr => StatusKind::Chr,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Status {
     pub hp: i32,
     pub mp: i32,
@@ -55,6 +126,138 @@ pub struct Status {
     pub chr: i32,
 }
 
+impl Status {
+    /// 各 StatusKind の値を返す関数から組み立てる
+    pub fn from_fn(f: impl Fn(StatusKind) -> i32) -> Status {
+        Status {
+            hp: f(StatusKind::Hp),
+            mp: f(StatusKind::Mp),
+            str: f(StatusKind::Str),
+            dex: f(StatusKind::Dex),
+            vit: f(StatusKind::Vit),
+            agi: f(StatusKind::Agi),
+            int: f(StatusKind::Int),
+            mnd: f(StatusKind::Mnd),
+            chr: f(StatusKind::Chr),
+        }
+    }
+
+    pub fn get(&self, kind: StatusKind) -> i32 {
+        self[kind]
+    }
+}
+
+impl Index<StatusKind> for Status {
+    type Output = i32;
+
+    fn index(&self, kind: StatusKind) -> &i32 {
+        match kind {
+            StatusKind::Hp => &self.hp,
+            StatusKind::Mp => &self.mp,
+            StatusKind::Str => &self.str,
+            StatusKind::Dex => &self.dex,
+            StatusKind::Vit => &self.vit,
+            StatusKind::Agi => &self.agi,
+            StatusKind::Int => &self.int,
+            StatusKind::Mnd => &self.mnd,
+            StatusKind::Chr => &self.chr,
+        }
+    }
+}
+
+impl IndexMut<StatusKind> for Status {
+    fn index_mut(&mut self, kind: StatusKind) -> &mut i32 {
+        match kind {
+            StatusKind::Hp => &mut self.hp,
+            StatusKind::Mp => &mut self.mp,
+            StatusKind::Str => &mut self.str,
+            StatusKind::Dex => &mut self.dex,
+            StatusKind::Vit => &mut self.vit,
+            StatusKind::Agi => &mut self.agi,
+            StatusKind::Int => &mut self.int,
+            StatusKind::Mnd => &mut self.mnd,
+            StatusKind::Chr => &mut self.chr,
+        }
+    }
+}
+
+// PackedStatus の各ステータスのビット幅 (StatusKind 順)。
+// HP/MP: 14 bit (0-16383)、能力値: 9 bit (0-511)。合計 91 bit のため u64 には収まらず u128 を使う。
+const PACKED_STATUS_BITS: [u32; StatusKind::COUNT] = [14, 14, 9, 9, 9, 9, 9, 9, 9];
+
+/// 大量のビルドを保持するための `Status` の省メモリ表現 (16 byte)。
+/// 各ステータスを `PACKED_STATUS_BITS` のビット幅で詰める。負の値や上限超えは表現できない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PackedStatus(u128);
+
+impl PackedStatus {
+    /// ステータスのビット位置 (下位から) とマスク
+    fn layout(kind: StatusKind) -> (u32, u128) {
+        let idx = kind as usize;
+        let shift = PACKED_STATUS_BITS[..idx].iter().sum();
+        (shift, (1u128 << PACKED_STATUS_BITS[idx]) - 1)
+    }
+
+    /// 指定ステータスで表現できる最大値
+    pub fn max_value(kind: StatusKind) -> i32 {
+        Self::layout(kind).1 as i32
+    }
+
+    pub fn get(&self, kind: StatusKind) -> i32 {
+        let (shift, mask) = Self::layout(kind);
+        ((self.0 >> shift) & mask) as i32
+    }
+}
+
+impl TryFrom<Status> for PackedStatus {
+    type Error = String;
+
+    fn try_from(status: Status) -> Result<Self, Self::Error> {
+        let mut packed = 0u128;
+        for &kind in StatusKind::VARIANTS {
+            let value = status.get(kind);
+            if !(0..=Self::max_value(kind)).contains(&value) {
+                return Err(format!(
+                    "{:?} must be between 0 and {} to pack: {}",
+                    kind,
+                    Self::max_value(kind),
+                    value
+                ));
+            }
+            let (shift, _) = Self::layout(kind);
+            packed |= (value as u128) << shift;
+        }
+        Ok(PackedStatus(packed))
+    }
+}
+
+impl From<PackedStatus> for Status {
+    fn from(packed: PackedStatus) -> Self {
+        Status::from_fn(|kind| packed.get(kind))
+    }
+}
+
+/// ステータス計算式のバージョン
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FormulaVersion {
+    /// サポートジョブをメインと同じグレードで計算していた版
+    V1,
+    /// サポートジョブのグレードを 1 段階下げて計算する版 (現行)
+    V2,
+}
+
+impl FormulaVersion {
+    /// 現在の `Chara::status` が採用している計算式
+    pub const CURRENT: FormulaVersion = FormulaVersion::V2;
+}
+
+/// 計算式バージョン付きのステータス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedStatus {
+    pub formula_version: FormulaVersion,
+    pub status: Status,
+}
+
 // Master Level bonus per level for each stat
 // HP: +7, MP: +2 (only if job has MP), BP stats: +1
 const MASTER_LV_BONUS: [i32; StatusKind::COUNT] = [
@@ -69,104 +272,19 @@ const MASTER_LV_BONUS: [i32; StatusKind::COUNT] = [
     1, // CHR
 ];
 
+/// マスターレベルによる加算。Lv99 以降の成長はここで扱う (`MAX_GROWTH_LV` を参照)
 pub fn calc_master_lv_bonus(kind: StatusKind, mlv: i32) -> i32 {
     MASTER_LV_BONUS[kind as usize] * mlv
 }
 
-// Merit point bonus per rank for each stat
-// HP/MP: +10 per rank, other stats: +1 per rank
-const MERIT_POINT_BONUS: [i32; StatusKind::COUNT] = [
-    10, // HP
-    10, // MP
-    1,  // STR
-    1,  // DEX
-    1,  // VIT
-    1,  // AGI
-    1,  // INT
-    1,  // MND
-    1,  // CHR
-];
-
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
-pub struct MeritPoints {
-    pub hp: i32,
-    pub mp: i32,
-    pub str_: i32,
-    pub dex: i32,
-    pub vit: i32,
-    pub agi: i32,
-    pub int: i32,
-    pub mnd: i32,
-    pub chr: i32,
-    /// 戦闘スキルメリット (キー: スキル名, 値: 0-8, +2/rank)
-    #[serde(default)]
-    pub combat_skill_merits: std::collections::BTreeMap<String, i32>,
-    /// 魔法スキルメリット (キー: スキル名, 値: 0-8, +2/rank)
-    #[serde(default)]
-    pub magic_skill_merits: std::collections::BTreeMap<String, i32>,
-    /// 敵対心+ (0-5, +1/rank)
-    #[serde(default)]
-    pub enmity_plus: i32,
-    /// 敵対心- (0-5, -1/rank)
-    #[serde(default)]
-    pub enmity_minus: i32,
-    /// クリティカルヒット率 (0-5, +1%/rank)
-    #[serde(default)]
-    pub critical_hit_rate: i32,
-    /// 被クリティカルヒット率 (0-5, -1%/rank)
-    #[serde(default)]
-    pub enemy_critical_hit_rate: i32,
-    /// 詠唱中断率 (0-5, -2%/rank)
-    #[serde(default)]
-    pub spell_interruption_rate: i32,
-    /// Store TP メリット (SAM 専用, 0-5, +1/rank)
-    #[serde(default)]
-    pub store_tp: i32,
-    /// ジョブ別メリットポイント (Group 1 + Group 2、各 8 カテゴリ × 0-5 rank)。
-    /// 現状ステータス計算には未反映（カテゴリ名・効果は別途扱い）。
-    /// キー: ジョブ短縮名（"War", "Sam", ...）
-    #[serde(default)]
-    pub job_merits: std::collections::BTreeMap<String, JobMerits>,
-}
-
-/// 単一ジョブのメリットポイント（Group 1 / Group 2、各 8 カテゴリ）
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
-pub struct JobMerits {
-    #[serde(default)]
-    pub group1: [i32; 8],
-    #[serde(default)]
-    pub group2: [i32; 8],
-}
-
-impl MeritPoints {
-    pub fn get(&self, kind: StatusKind) -> i32 {
-        match kind {
-            StatusKind::Hp => self.hp,
-            StatusKind::Mp => self.mp,
-            StatusKind::Str => self.str_,
-            StatusKind::Dex => self.dex,
-            StatusKind::Vit => self.vit,
-            StatusKind::Agi => self.agi,
-            StatusKind::Int => self.int,
-            StatusKind::Mnd => self.mnd,
-            StatusKind::Chr => self.chr,
-        }
-    }
-
-    pub fn status_bonus(&self, kind: StatusKind) -> i32 {
-        let rank = self.get(kind);
-        assert!(rank >= 0 && rank <= 15, "merit point rank must be between 0 and 15");
-        MERIT_POINT_BONUS[kind as usize] * rank
-    }
-}
-
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct BonusStats {
     #[serde(default)]
     pub hp: i32,
     #[serde(default)]
     pub mp: i32,
-    #[serde(default)]
+    /// JSON キーは `str` (従来の `str_` も読み込み時のみ受け付ける)
+    #[serde(default, rename = "str", alias = "str_")]
     pub str_: i32,
     #[serde(default)]
     pub dex: i32,
@@ -250,9 +368,63 @@ pub struct BonusStats {
     /// 全スロット共通で加算されるスキルボーナス（非武器スロット装備、および武器スロット装備の非武器スキル）
     #[serde(default)]
     pub skill_bonus_global: std::collections::BTreeMap<String, i32>,
+    /// 装備の「ステータス変換」効果 (例: STR の 50% を攻撃力に加算)
+    #[serde(default)]
+    pub conversions: Vec<Conversion>,
+}
+
+/// ステータス変換の変換先となる派生ステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModKind {
+    Attack,
+    Accuracy,
+    RangedAttack,
+    RangedAccuracy,
+    Defense,
+    Evasion,
+    MagicAttack,
+}
+
+/// 装備の「ステータス変換」効果。`from` の値 × `ratio` (端数切り捨て) を `to` に加算する
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Conversion {
+    pub from: StatusKind,
+    pub to: ModKind,
+    pub ratio: f32,
+}
+
+// `Chara` の Eq/Hash のため、`ratio` はビット列で比較する (NaN 同士も等しく、0.0 と -0.0 は区別する)
+impl PartialEq for Conversion {
+    fn eq(&self, other: &Self) -> bool {
+        (self.from, self.to, self.ratio.to_bits()) == (other.from, other.to, other.ratio.to_bits())
+    }
+}
+
+impl Eq for Conversion {}
+
+impl std::hash::Hash for Conversion {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.from, self.to, self.ratio.to_bits()).hash(state);
+    }
+}
+
+impl Conversion {
+    /// 変換元の値から加算量を求める
+    pub fn amount(&self, from_value: i32) -> i32 {
+        (from_value as f32 * self.ratio).floor() as i32
+    }
 }
 
 impl BonusStats {
+    /// `to` を変換先とする変換効果の加算量合計。`status` は変換元ステータスの最終値を返す
+    pub fn conversion_total(&self, to: ModKind, status: impl Fn(StatusKind) -> i32) -> i32 {
+        self.conversions
+            .iter()
+            .filter(|c| c.to == to)
+            .map(|c| c.amount(status(c.from)))
+            .sum()
+    }
+
     pub fn get(&self, kind: StatusKind) -> i32 {
         match kind {
             StatusKind::Hp => self.hp,
@@ -266,55 +438,248 @@ impl BonusStats {
             StatusKind::Chr => self.chr,
         }
     }
+
+    pub fn get_mut(&mut self, kind: StatusKind) -> &mut i32 {
+        match kind {
+            StatusKind::Hp => &mut self.hp,
+            StatusKind::Mp => &mut self.mp,
+            StatusKind::Str => &mut self.str_,
+            StatusKind::Dex => &mut self.dex,
+            StatusKind::Vit => &mut self.vit,
+            StatusKind::Agi => &mut self.agi,
+            StatusKind::Int => &mut self.int,
+            StatusKind::Mnd => &mut self.mnd,
+            StatusKind::Chr => &mut self.chr,
+        }
+    }
+}
+
+/// ステータスごとの上限値 (装備・食事込み)。デフォルトはキャップなし (i32::MAX)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusCap {
+    caps: EnumMap<StatusKind, i32>,
+}
+
+impl Default for StatusCap {
+    fn default() -> Self {
+        Self {
+            caps: EnumMap::from_fn(|_| i32::MAX),
+        }
+    }
+}
+
+impl StatusCap {
+    /// 指定ステータスの上限を設定する
+    pub fn with_cap(mut self, kind: StatusKind, cap: i32) -> Self {
+        self.caps[kind] = cap;
+        self
+    }
+
+    pub fn cap(&self, kind: StatusKind) -> i32 {
+        self.caps[kind]
+    }
+
+    /// 上限を超えた値を上限に丸める
+    pub fn clamp_to_cap(&self, kind: StatusKind, value: i32) -> i32 {
+        value.min(self.caps[kind])
+    }
 }
 
 impl Grade {
-    pub fn base(&self, kind: StatusKind) -> f32 {
-        match kind {
-            StatusKind::Hp | StatusKind::Mp => GRADE_COEFFICIENTS.hpmp[*self][0],
-            _ => GRADE_COEFFICIENTS.bp[*self][0],
+    /// 1 段階下のグレード (G は G のまま)
+    pub fn down(&self) -> Grade {
+        match self {
+            Grade::A => Grade::B,
+            Grade::B => Grade::C,
+            Grade::C => Grade::D,
+            Grade::D => Grade::E,
+            Grade::E => Grade::F,
+            Grade::F | Grade::G => Grade::G,
         }
     }
 
+    /// HP/MP の係数テーブルの行 [Base, Lv2-60, Lv61-75, Lv76-99, 30+]
+    pub fn hpmp_row(&self) -> &'static [f32; 5] {
+        let coefficients: &'static GradeCoefficients = &GRADE_COEFFICIENTS;
+        &coefficients.hpmp[*self]
+    }
+
+    /// 基本ステータスの係数テーブルの行 [Base, Lv2-60, Lv61-75, Lv76-99]
+    pub fn bp_row(&self) -> &'static [f32; 4] {
+        let coefficients: &'static GradeCoefficients = &GRADE_COEFFICIENTS;
+        &coefficients.bp[*self]
+    }
+
+    /// 全ステータス共通の 4 列 [Base, Lv2-60, Lv61-75, Lv76-99]。
+    /// HP/MP の 30+ 係数は含まない (`coef_30plus` で取得する)
+    pub fn coef_row(&self, kind: StatusKind) -> [f32; 4] {
+        match kind.class() {
+            StatusClass::HpMp(_) => {
+                let [base, to60, to75, to99, _] = *self.hpmp_row();
+                [base, to60, to75, to99]
+            }
+            StatusClass::Bp(_) => *self.bp_row(),
+        }
+    }
+
+    pub fn base(&self, kind: StatusKind) -> f32 {
+        self.coef_at(kind, LevelBand::Base)
+    }
+
+    /// `lv` は `level_tier` でレベル帯に変換するため、範囲外の値でも panic しない
     pub fn coef(&self, kind: StatusKind, lv: i32) -> f32 {
-        let idx = match lv {
-            2..=60 => 1,
-            61..=75 => 2,
-            76..=99 => 3,
-            _ => panic!("lv must be between 0 and 99: {}", lv),
-        };
+        self.coef_at(kind, level_tier(lv))
+    }
 
-        match kind {
-            StatusKind::Hp | StatusKind::Mp => GRADE_COEFFICIENTS.hpmp[*self][idx],
-            _ => GRADE_COEFFICIENTS.bp[*self][idx],
+    /// レベル帯を指定して係数を取得する (Base は base 値)。
+    /// HP/MP の 30+ 係数は `coef_30plus` で取得する
+    pub fn coef_at(&self, kind: StatusKind, band: LevelBand) -> f32 {
+        let [base, to60, to75, to99] = self.coef_row(kind);
+        match band {
+            LevelBand::Base => base,
+            LevelBand::To60 => to60,
+            LevelBand::To75 => to75,
+            LevelBand::To99 => to99,
         }
     }
 
-    pub fn coef_30plus(&self, kind: StatusKind) -> f32 {
-        match kind {
-            StatusKind::Hp | StatusKind::Mp => GRADE_COEFFICIENTS.hpmp[*self][4],
-            _ => panic!("coef_30plus is not applicable for BP"),
+    /// HP/MP の 30+ 係数。基本ステータスには存在しないため `HpMpKind` のみ受け付ける
+    pub fn coef_30plus(&self, _kind: HpMpKind) -> f32 {
+        let [.., coef_30plus] = *self.hpmp_row();
+        coef_30plus
+    }
+}
+
+/// グレード表 (`data/grade_coefficients.json`・`race_status_grades.json`・`job_status_grades.json`) の版。
+/// 各 JSON の `version` を同じ順に `.` で連結したもの。データを修正したら JSON の `version` とあわせて更新する。
+pub const DATA_VERSION: &str = "1.1.1";
+
+/// グレード表 JSON のいずれかを最後に変更したコミットの日付 (YYYY-MM-DD)。
+/// build.rs が `git log` から取得する。git 履歴の無い環境でビルドした場合は "unknown"
+pub const DATA_UPDATED: &str = env!("FF11SIM_GRADE_DATA_UPDATED");
+
+/// グレード表の出典となるデータファイル (リポジトリルートからの相対パス、`, ` 区切り)。
+/// build.rs が読み込んだファイルの一覧
+pub const DATA_SOURCE: &str = env!("FF11SIM_GRADE_DATA_FILES");
+
+/// `formula::LEVEL_BANDS` の各項に対応するレベル帯
+const LEVEL_BAND_KINDS: [LevelBand; 3] = [LevelBand::To60, LevelBand::To75, LevelBand::To99];
+
+/// ステータス計算のレベル帯
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCount, EnumIter, VariantArray, Enum, Serialize, Deserialize)]
+pub enum LevelBand {
+    /// Lv1 (base 値のみ)
+    Base,
+    /// Lv2-60
+    To60,
+    /// Lv61-75
+    To75,
+    /// Lv76-99
+    To99,
+}
+
+impl LevelBand {
+    /// レベルが属する帯。範囲外のレベルは最も近い帯とする (lv <= 1 → Base, lv >= 100 → To99)。
+    pub fn of(lv: i32) -> LevelBand {
+        match lv {
+            ..=1 => LevelBand::Base,
+            2..=60 => LevelBand::To60,
+            61..=75 => LevelBand::To75,
+            76.. => LevelBand::To99,
         }
     }
 }
 
+/// レベルに対応する成長係数のレベル帯 (To60 / To75 / To99)。
+/// 範囲外のレベルは最も近いレベル帯に丸める (lv <= 1 → To60, lv >= 100 → To99)。
+pub fn level_tier(lv: i32) -> LevelBand {
+    match LevelBand::of(lv) {
+        LevelBand::Base | LevelBand::To60 => LevelBand::To60,
+        band => band,
+    }
+}
+
+/// グレード単独のステータス値。0.5 単位の端数を保持したまま返す。
+/// 種族・メインジョブ・サポートジョブの値は丸めずに合算し、合計を最後に 1 回だけ整数へ切り捨てる
+/// (実機準拠。`Chara::status` を参照)。グレードごとに切り捨ててから合算すると 1 小さくなる場合がある。
 pub fn calc_status(kind: StatusKind, grade: Grade, lv: i32) -> f32 {
-    if lv == 0 {
-        return 0.0;
+    calc_status_with(kind, grade, lv, RoundingStrategy::default())
+}
+
+pub fn calc_status_with(
+    kind: StatusKind,
+    grade: Grade,
+    lv: i32,
+    rounding: RoundingStrategy,
+) -> f32 {
+    calc_class_status(kind.class(), grade, lv, rounding)
+}
+
+/// HP/MP の `calc_status` (30+ 項を含む)
+pub fn calc_hpmp_status(kind: HpMpKind, grade: Grade, lv: i32) -> f32 {
+    calc_class_status(
+        StatusClass::HpMp(kind),
+        grade,
+        lv,
+        RoundingStrategy::default(),
+    )
+}
+
+/// 基本ステータスの `calc_status` (30+ 項を持たない)
+pub fn calc_bp_status(kind: BpKind, grade: Grade, lv: i32) -> f32 {
+    calc_class_status(
+        StatusClass::Bp(kind),
+        grade,
+        lv,
+        RoundingStrategy::default(),
+    )
+}
+
+/// 30+ 係数は `HpMpKind` からしか取得できないため、基本ステータスに 30+ 項が加わることはない
+fn calc_class_status(class: StatusClass, grade: Grade, lv: i32, rounding: RoundingStrategy) -> f32 {
+    match class {
+        StatusClass::HpMp(kind) => status_from_row(
+            &grade.coef_row(kind.into()),
+            Some(grade.coef_30plus(kind)),
+            lv,
+            rounding,
+        ),
+        StatusClass::Bp(kind) => status_from_row(&grade.coef_row(kind.into()), None, lv, rounding),
     }
+}
 
-    // truncate for each term with 0.5
-    let mut ret = grade.base(kind);
-    ret += (grade.coef(kind, 2) * std::cmp::min(lv - 1, 59) as f32 * 2.0).floor() / 2.0;
-    ret += (grade.coef(kind, 61) * std::cmp::min(std::cmp::max(lv - 60, 0), 15) as f32 * 2.0)
-        .floor()
-        / 2.0;
-    ret += (grade.coef(kind, 76) * std::cmp::max(lv - 75, 0) as f32 * 2.0).floor() / 2.0;
+/// `calc_status` (PerBand 丸め) をレベル帯ごとの寄与に分解する。
+/// Base は base 値、各帯はその帯のレベルで得た増分。HP/MP の 30+ 項は Lv31 以降の各帯に按分する。
+/// 全帯の合計は `calc_status` と一致する。
+pub fn calc_status_by_band(kind: StatusKind, grade: Grade, lv: i32) -> EnumMap<LevelBand, f32> {
+    let mut bands = EnumMap::default();
+    if lv == 0 {
+        return bands;
+    }
 
-    if kind == StatusKind::Hp || kind == StatusKind::Mp {
-        ret += (grade.coef_30plus(kind) * std::cmp::max(lv - 30, 0) as f32 * 2.0).floor() / 2.0;
+    bands[LevelBand::Base] = grade.base(kind);
+    let mut term_30plus_prev = 0.0;
+    for (band, (start, end, _)) in LEVEL_BAND_KINDS.into_iter().zip(LEVEL_BANDS) {
+        bands[band] = floor_half(grade.coef_at(kind, band) * levels_in_band(lv, start, end) as f32);
+        if let StatusClass::HpMp(hpmp) = kind.class() {
+            // 30+ 項は帯の終わりまでの累積値の差分として按分し、合計を calc_status に揃える
+            let levels_30plus = levels_in_band(lv.min(end), HPMP_30PLUS_START, MAX_GROWTH_LV);
+            let term_30plus = floor_half(grade.coef_30plus(hpmp) * levels_30plus as f32);
+            bands[band] += term_30plus - term_30plus_prev;
+            term_30plus_prev = term_30plus;
+        }
     }
-    return ret;
+    bands
+}
+
+/// 2 つの丸め方式で `calc_status` の結果が異なるレベル (1..=99) の一覧
+pub fn rounding_diff_levels(kind: StatusKind, grade: Grade) -> Vec<i32> {
+    (1..=99)
+        .filter(|&lv| {
+            calc_status_with(kind, grade, lv, RoundingStrategy::PerBand)
+                != calc_status_with(kind, grade, lv, RoundingStrategy::Final)
+        })
+        .collect()
 }
 
 /// 防御力を計算する。
@@ -437,6 +802,312 @@ pub fn calc_ranged_accuracy(agi: i32, weapon_skill: i32, equip_ranged_accuracy:
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_rounding_strategy_per_band_matches_known_values() {
+        // 既存の検証値 (Hum War99: STR 37.5 + 45 → 82) は帯ごとの切り捨てでのみ再現する
+        let race = calc_status(StatusKind::Str, Grade::D, 99);
+        assert_eq!(race, 37.5);
+        assert_eq!(
+            calc_status_with(StatusKind::Str, Grade::D, 99, RoundingStrategy::PerBand),
+            race
+        );
+        // 最終切り捨て: 3 + 20.65 + 5.1 + 9.36 = 38.11 → 38.0
+        assert_eq!(
+            calc_status_with(StatusKind::Str, Grade::D, 99, RoundingStrategy::Final),
+            38.0
+        );
+    }
+
+    #[test]
+    fn test_rounding_diff_levels() {
+        // HP/MP の係数は整数なので丸め方式で差は出ない
+        for grade in Grade::VARIANTS {
+            assert!(rounding_diff_levels(StatusKind::Hp, *grade).is_empty());
+        }
+        // BP は小数係数のため、複数の帯にまたがるレベルで差が出る
+        let levels = rounding_diff_levels(StatusKind::Str, Grade::D);
+        assert!(levels.contains(&99));
+        // Lv1 は base のみなので差は出ない
+        assert!(!levels.contains(&1));
+    }
+
+    /// テーブル駆動化する前の calc_status (回帰テスト用の参照実装)
+    fn calc_status_reference(
+        kind: StatusKind,
+        grade: Grade,
+        lv: i32,
+        rounding: RoundingStrategy,
+    ) -> f32 {
+        if lv == 0 {
+            return 0.0;
+        }
+        let term_30plus = match kind.class() {
+            StatusClass::HpMp(hpmp) => grade.coef_30plus(hpmp) * std::cmp::max(lv - 30, 0) as f32,
+            StatusClass::Bp(_) => 0.0,
+        };
+        let terms = [
+            grade.coef(kind, 2) * std::cmp::min(lv - 1, 59) as f32,
+            grade.coef(kind, 61) * (lv - 60).clamp(0, 15) as f32,
+            grade.coef(kind, 76) * std::cmp::max(lv - 75, 0) as f32,
+            term_30plus,
+        ];
+        let truncate = |x: f32| (x * 2.0).floor() / 2.0;
+        match rounding {
+            RoundingStrategy::PerBand => {
+                grade.base(kind) + terms.into_iter().map(truncate).sum::<f32>()
+            }
+            RoundingStrategy::Final => truncate(grade.base(kind) + terms.into_iter().sum::<f32>()),
+        }
+    }
+
+    #[test]
+    fn test_calc_status_table_driven_matches_reference() {
+        for &kind in StatusKind::VARIANTS {
+            for &grade in Grade::VARIANTS {
+                for lv in 0..=99 {
+                    for rounding in [RoundingStrategy::PerBand, RoundingStrategy::Final] {
+                        assert_eq!(
+                            calc_status_with(kind, grade, lv, rounding),
+                            calc_status_reference(kind, grade, lv, rounding),
+                            "{:?} {:?} Lv{} {:?}",
+                            kind,
+                            grade,
+                            lv,
+                            rounding
+                        );
+                    }
+                }
+            }
+        }
+        // 既存の検証値 (Hum War99: HP 485 + 675, STR 37.5 + 45)
+        assert_eq!(calc_status(StatusKind::Hp, Grade::D, 99), 485.0);
+        assert_eq!(calc_status(StatusKind::Hp, Grade::B, 99), 675.0);
+        assert_eq!(calc_status(StatusKind::Str, Grade::D, 99), 37.5);
+        assert_eq!(calc_status(StatusKind::Str, Grade::A, 99), 45.0);
+    }
+
+    #[test]
+    fn test_calc_status_by_band_sums_to_calc_status() {
+        for &kind in StatusKind::VARIANTS {
+            for &grade in Grade::VARIANTS {
+                for lv in 1..=99 {
+                    let bands = calc_status_by_band(kind, grade, lv);
+                    let total: f32 = bands.values().sum();
+                    assert_eq!(
+                        total,
+                        calc_status(kind, grade, lv),
+                        "{:?} {:?} Lv{}",
+                        kind,
+                        grade,
+                        lv
+                    );
+                    // 未到達の帯は 0
+                    for &band in LevelBand::VARIANTS {
+                        if band as usize > LevelBand::of(lv) as usize {
+                            assert_eq!(bands[band], 0.0, "{:?} {:?} Lv{}", kind, grade, lv);
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            calc_status_by_band(StatusKind::Hp, Grade::A, 0),
+            EnumMap::default()
+        );
+    }
+
+    #[test]
+    fn test_calc_status_above_max_growth_lv() {
+        // Lv99 を超えるレベルは Lv99 と同じ値 (panic・外挿しない)
+        for &kind in StatusKind::VARIANTS {
+            for &grade in Grade::VARIANTS {
+                let lv99 = calc_status(kind, grade, MAX_GROWTH_LV);
+                for lv in [100, 150, i32::MAX] {
+                    assert_eq!(
+                        calc_status(kind, grade, lv),
+                        lv99,
+                        "{:?} {:?} Lv{}",
+                        kind,
+                        grade,
+                        lv
+                    );
+                    assert_eq!(
+                        calc_status_by_band(kind, grade, lv),
+                        calc_status_by_band(kind, grade, MAX_GROWTH_LV)
+                    );
+                }
+            }
+        }
+        // 99 以降の成長はマスターレベルで加算する
+        assert_eq!(
+            calc_status(StatusKind::Hp, Grade::D, 149),
+            calc_status(StatusKind::Hp, Grade::D, 99)
+        );
+        assert_eq!(calc_master_lv_bonus(StatusKind::Hp, 50), 350);
+    }
+
+    #[test]
+    fn test_status_index() {
+        let mut status = Status::from_fn(|kind| kind as i32 * 10);
+        assert_eq!(status[StatusKind::Hp], status.hp);
+        assert_eq!(status[StatusKind::Mp], status.mp);
+        assert_eq!(status[StatusKind::Str], status.str);
+        assert_eq!(status[StatusKind::Dex], status.dex);
+        assert_eq!(status[StatusKind::Vit], status.vit);
+        assert_eq!(status[StatusKind::Agi], status.agi);
+        assert_eq!(status[StatusKind::Int], status.int);
+        assert_eq!(status[StatusKind::Mnd], status.mnd);
+        assert_eq!(status[StatusKind::Chr], status.chr);
+
+        for &kind in StatusKind::VARIANTS {
+            assert_eq!(status[kind], kind as i32 * 10);
+            status[kind] += 1;
+            assert_eq!(status.get(kind), kind as i32 * 10 + 1);
+        }
+    }
+
+    #[test]
+    fn test_packed_status_roundtrip() {
+        let status = Status {
+            hp: 3210,
+            mp: 1662,
+            str: 146,
+            dex: 138,
+            vit: 136,
+            agi: 138,
+            int: 125,
+            mnd: 126,
+            chr: 133,
+        };
+        let packed = PackedStatus::try_from(status.clone()).unwrap();
+        assert_eq!(packed.get(StatusKind::Mp), 1662);
+        assert_eq!(Status::from(packed), status);
+        assert_eq!(std::mem::size_of::<PackedStatus>(), 16);
+
+        // 各ステータスの上限値・0 も往復で保たれる
+        let max = Status::from_fn(PackedStatus::max_value);
+        assert_eq!(max.hp, 16383);
+        assert_eq!(max.str, 511);
+        assert_eq!(
+            Status::from(PackedStatus::try_from(max.clone()).unwrap()),
+            max
+        );
+        let zero = Status::from_fn(|_| 0);
+        assert_eq!(
+            Status::from(PackedStatus::try_from(zero.clone()).unwrap()),
+            zero
+        );
+    }
+
+    #[test]
+    fn test_packed_status_out_of_range() {
+        let base = Status::from_fn(|_| 100);
+        let over = Status {
+            str: 512,
+            ..base.clone()
+        };
+        let err = PackedStatus::try_from(over).unwrap_err();
+        assert!(err.contains("Str"), "{}", err);
+        assert!(PackedStatus::try_from(Status {
+            hp: 16384,
+            ..base.clone()
+        })
+        .is_err());
+        assert!(PackedStatus::try_from(Status { chr: -1, ..base }).is_err());
+    }
+
+    #[test]
+    fn test_level_tier() {
+        assert_eq!(level_tier(0), LevelBand::To60);
+        assert_eq!(level_tier(1), LevelBand::To60);
+        assert_eq!(level_tier(2), LevelBand::To60);
+        assert_eq!(level_tier(60), LevelBand::To60);
+        assert_eq!(level_tier(61), LevelBand::To75);
+        assert_eq!(level_tier(75), LevelBand::To75);
+        assert_eq!(level_tier(76), LevelBand::To99);
+        assert_eq!(level_tier(99), LevelBand::To99);
+        assert_eq!(level_tier(100), LevelBand::To99);
+    }
+
+    #[test]
+    fn test_conversion_total() {
+        let bonus = BonusStats {
+            conversions: vec![
+                Conversion {
+                    from: StatusKind::Str,
+                    to: ModKind::Attack,
+                    ratio: 0.5,
+                },
+                Conversion {
+                    from: StatusKind::Dex,
+                    to: ModKind::Accuracy,
+                    ratio: 0.1,
+                },
+            ],
+            ..BonusStats::default()
+        };
+        let status = |kind| match kind {
+            StatusKind::Str => 101,
+            StatusKind::Dex => 99,
+            _ => 0,
+        };
+        // 101 × 0.5 = 50.5 → 50
+        assert_eq!(bonus.conversion_total(ModKind::Attack, status), 50);
+        // 99 × 0.1 = 9.9 → 9
+        assert_eq!(bonus.conversion_total(ModKind::Accuracy, status), 9);
+        assert_eq!(bonus.conversion_total(ModKind::Defense, status), 0);
+    }
+
+    #[test]
+    fn test_status_cap_default_is_uncapped() {
+        let cap = StatusCap::default();
+        for kind in StatusKind::VARIANTS {
+            assert_eq!(cap.cap(*kind), i32::MAX);
+            assert_eq!(cap.clamp_to_cap(*kind, 9999), 9999);
+        }
+    }
+
+    #[test]
+    fn test_status_cap_custom() {
+        let cap = StatusCap::default()
+            .with_cap(StatusKind::Str, 300)
+            .with_cap(StatusKind::Hp, 5000);
+        assert_eq!(cap.clamp_to_cap(StatusKind::Str, 350), 300);
+        assert_eq!(cap.clamp_to_cap(StatusKind::Str, 300), 300);
+        assert_eq!(cap.clamp_to_cap(StatusKind::Str, 120), 120);
+        assert_eq!(cap.clamp_to_cap(StatusKind::Hp, 6000), 5000);
+        // 未設定のステータスはキャップなし
+        assert_eq!(cap.clamp_to_cap(StatusKind::Dex, 350), 350);
+    }
+
+    #[test]
+    fn test_level_band_of() {
+        assert_eq!(LevelBand::of(0), LevelBand::Base);
+        assert_eq!(LevelBand::of(1), LevelBand::Base);
+        assert_eq!(LevelBand::of(2), LevelBand::To60);
+        assert_eq!(LevelBand::of(60), LevelBand::To60);
+        assert_eq!(LevelBand::of(61), LevelBand::To75);
+        assert_eq!(LevelBand::of(75), LevelBand::To75);
+        assert_eq!(LevelBand::of(76), LevelBand::To99);
+        assert_eq!(LevelBand::of(99), LevelBand::To99);
+        assert_eq!(LevelBand::of(100), LevelBand::To99);
+    }
+
+    #[test]
+    fn test_coef_does_not_panic_out_of_range() {
+        for grade in Grade::VARIANTS {
+            for kind in StatusKind::VARIANTS {
+                assert_eq!(grade.coef(*kind, 0), grade.coef(*kind, 2));
+                assert_eq!(grade.coef(*kind, 1), grade.coef(*kind, 2));
+                assert_eq!(grade.coef(*kind, 100), grade.coef(*kind, 99));
+                // calc_status も同様に panic しない
+                calc_status(*kind, *grade, 0);
+                calc_status(*kind, *grade, 1);
+                calc_status(*kind, *grade, 100);
+            }
+        }
+    }
+
     #[test]
     fn test_calc_defense_lv99() {
         // VIT=100, Lv=99, equip=0 → floor(100*1.5)=150, α=18+(99-89)/2=23, total=150+99+23+0=272
@@ -563,4 +1234,89 @@ mod tests {
         assert_eq!(ranged_accuracy_skill_term(600), 200 + 360); // 560 (近接 540 と差が出る)
         assert_eq!(ranged_accuracy_skill_term(800), 200 + 540); // 740
     }
+
+    #[test]
+    fn test_data_version_matches_json() {
+        assert_eq!(
+            DATA_VERSION,
+            crate::data_loader::GRADE_DATA_VERSION.as_str()
+        );
+        let is_date = DATA_UPDATED.split('-').map(str::len).eq([4, 2, 2])
+            && DATA_UPDATED.chars().all(|c| c == '-' || c.is_ascii_digit());
+        assert!(DATA_UPDATED == "unknown" || is_date, "{}", DATA_UPDATED);
+        // 出典のファイルはすべて実在し、DATA_VERSION を構成する JSON と同じ 3 つ
+        let files: Vec<&str> = DATA_SOURCE.split(", ").collect();
+        assert_eq!(files.len(), 3);
+        for file in files {
+            let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(file);
+            assert!(path.exists(), "{}", file);
+        }
+    }
+
+    #[test]
+    fn test_status_class_round_trip() {
+        for &kind in StatusKind::VARIANTS {
+            let back = match kind.class() {
+                StatusClass::HpMp(hpmp) => StatusKind::from(hpmp),
+                StatusClass::Bp(bp) => StatusKind::from(bp),
+            };
+            assert_eq!(back, kind);
+        }
+        assert_eq!(
+            HpMpKind::VARIANTS.len() + BpKind::VARIANTS.len(),
+            StatusKind::COUNT
+        );
+    }
+
+    #[test]
+    fn test_typed_calc_status_matches() {
+        for &grade in Grade::VARIANTS {
+            for lv in 0..=99 {
+                for &kind in HpMpKind::VARIANTS {
+                    assert_eq!(
+                        calc_hpmp_status(kind, grade, lv),
+                        calc_status(kind.into(), grade, lv)
+                    );
+                }
+                for &kind in BpKind::VARIANTS {
+                    assert_eq!(
+                        calc_bp_status(kind, grade, lv),
+                        calc_status(kind.into(), grade, lv)
+                    );
+                }
+            }
+            // HP と MP は同じ係数表を使う
+            assert_eq!(
+                grade.coef_30plus(HpMpKind::Hp),
+                grade.coef_30plus(HpMpKind::Mp)
+            );
+        }
+        // 既存の値を維持: Hum(D) Lv99 HP 485 / STR 37.5
+        assert_eq!(calc_hpmp_status(HpMpKind::Hp, Grade::D, 99), 485.0);
+        assert_eq!(calc_bp_status(BpKind::Str, Grade::D, 99), 37.5);
+    }
+
+    #[test]
+    fn test_coef_row_matches_tables() {
+        for &grade in Grade::VARIANTS {
+            for &kind in StatusKind::VARIANTS {
+                let expected: &[f32] = match kind.class() {
+                    StatusClass::HpMp(_) => &GRADE_COEFFICIENTS.hpmp[grade][..4],
+                    StatusClass::Bp(_) => &GRADE_COEFFICIENTS.bp[grade],
+                };
+                let row = grade.coef_row(kind);
+                assert_eq!(row, expected, "{:?} {:?}", grade, kind);
+                // LevelBand の定義順が係数の列順
+                for (&band, &coef) in LevelBand::VARIANTS.iter().zip(expected) {
+                    assert_eq!(grade.coef_at(kind, band), coef);
+                }
+                assert_eq!(grade.base(kind), expected[0]);
+            }
+            for &kind in HpMpKind::VARIANTS {
+                assert_eq!(grade.coef_30plus(kind), GRADE_COEFFICIENTS.hpmp[grade][4]);
+            }
+        }
+    }
 }
@@ -7,14 +7,15 @@ use wasm_bindgen::prelude::*;
 
 use crate::chara::Chara;
 use crate::character_profile::CharacterProfile;
+use crate::error::{ApiError, ErrorCode};
 use crate::gift::Gift;
 use crate::job::{Job, JobTrait};
-use crate::job_points::{calc_gift_bonuses, calc_jp_category_bonuses, calc_war_da_gift_bonus};
+use crate::job_points::{calc_gift_bonuses, calc_war_da_gift_bonus};
 use crate::race::Race;
 use crate::skills::{
     default_skills, effective_skill, job_skill_rank, weapon_skill_from_item_id, SkillKind,
 };
-use crate::status::{BonusStats, MeritPoints, StatusKind};
+use crate::status::{BonusStats, MeritPoints, ModKind, StatusKind, DATA_VERSION};
 
 /// BTreeMap を JS Map ではなく plain object として出力するためのシリアライザ
 fn object_serializer() -> Serializer {
@@ -27,10 +28,29 @@ pub fn init() {
     console_error_panic_hook::set_once();
 }
 
+/// クレートのバージョン (Cargo.toml の `version`)。
+/// JS: get_version() → "0.1.0"
+#[wasm_bindgen]
+pub fn get_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+/// 計算式データ (グレード表) のバージョン (`status::DATA_VERSION`)。
+/// データ更新時の UI キャッシュ破棄の判断に使う。
+/// JS: get_data_version() → "1.1.1"
+#[wasm_bindgen]
+pub fn get_data_version() -> String {
+    DATA_VERSION.to_string()
+}
+
+/// ステータス計算結果。JSON のキーは Rust のフィールド名ではなく `str` などの自然な名前。
+/// 移行の注意: 以前は STR を `str_` キーで返していた。フロントは `result.str` を参照すること
+/// (読み込み時のみ `str_` も受け付ける)。
 #[derive(Serialize, Deserialize)]
 pub struct StatusResult {
     pub hp: i32,
     pub mp: i32,
+    #[serde(rename = "str", alias = "str_")]
     pub str_: i32,
     pub dex: i32,
     pub vit: i32,
@@ -328,54 +348,24 @@ pub struct StatusResult {
     pub ranged_weapon_skill: Option<String>,
     /// 装備レンジ武器のスキル有効値
     pub ranged_weapon_skill_value: Option<i32>,
+    /// 入力が不正でデフォルト構成 (Hum/War99) にフォールバックした場合の警告
+    /// (`calculate_status` の `default_on_error` 指定時のみ)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 fn str_to_race(s: &str) -> Option<Race> {
-    match s.to_lowercase().as_str() {
-        "hum" | "hume" => Some(Race::Hum),
-        "elv" | "elvaan" => Some(Race::Elv),
-        "tar" | "tarutaru" => Some(Race::Tar),
-        "mit" | "mithra" => Some(Race::Mit),
-        "gal" | "galka" => Some(Race::Gal),
-        _ => None,
-    }
-}
-
-fn str_to_job(s: &str) -> Option<Job> {
-    match s.to_lowercase().as_str() {
-        "war" | "warrior" => Some(Job::War),
-        "mnk" | "monk" => Some(Job::Mnk),
-        "whm" | "white mage" => Some(Job::Whm),
-        "blm" | "black mage" => Some(Job::Blm),
-        "rdm" | "red mage" => Some(Job::Rdm),
-        "thf" | "thief" => Some(Job::Thf),
-        "pld" | "paladin" => Some(Job::Pld),
-        "drk" | "dark knight" => Some(Job::Drk),
-        "bst" | "beastmaster" => Some(Job::Bst),
-        "brd" | "bard" => Some(Job::Brd),
-        "rng" | "ranger" => Some(Job::Rng),
-        "sam" | "samurai" => Some(Job::Sam),
-        "nin" | "ninja" => Some(Job::Nin),
-        "drg" | "dragoon" => Some(Job::Drg),
-        "smn" | "summoner" => Some(Job::Smn),
-        "blu" | "blue mage" => Some(Job::Blu),
-        "cor" | "corsair" => Some(Job::Cor),
-        "pup" | "puppetmaster" => Some(Job::Pup),
-        "dnc" | "dancer" => Some(Job::Dnc),
-        "sch" | "scholar" => Some(Job::Sch),
-        "geo" | "geomancer" => Some(Job::Geo),
-        "run" | "rune fencer" => Some(Job::Run),
-        _ => None,
-    }
+    s.parse().ok()
 }
 
+/// メリットポイント入力。STR は `str` キーで指定する (従来の `str_` も受け付ける)
 #[derive(Serialize, Deserialize, Default)]
 pub struct MeritPointsInput {
     #[serde(default)]
     pub hp: i32,
     #[serde(default)]
     pub mp: i32,
-    #[serde(default)]
+    #[serde(default, rename = "str", alias = "str_")]
     pub str_: i32,
     #[serde(default)]
     pub dex: i32,
@@ -434,8 +424,183 @@ impl From<MeritPointsInput> for MeritPoints {
     }
 }
 
+/// calculate_with_gear のキャラクター指定
+#[derive(Serialize, Deserialize)]
+pub struct CharaInput {
+    pub race: String,
+    pub main_job: String,
+    pub main_lv: i32,
+    #[serde(default)]
+    pub support_job: Option<String>,
+    #[serde(default)]
+    pub support_lv: Option<i32>,
+    #[serde(default)]
+    pub master_lv: i32,
+    #[serde(default)]
+    pub merit_points: MeritPointsInput,
+}
+
+/// calculate_with_gear の装備指定: スロット名 → (BonusStats のキー → 補正値)
+/// 例: { "head": { "str": 33, "attack": 70 }, "body": { "str": 43 } }
+pub type GearInput = BTreeMap<String, BTreeMap<String, i32>>;
+
+/// 全スロットの補正を合算して BonusStats にする。BonusStats に無いキーはエラー
+fn gear_to_bonus_stats(gear: &GearInput) -> Result<BonusStats, ApiError> {
+    let mut total: BTreeMap<&str, i32> = BTreeMap::new();
+    for mods in gear.values() {
+        for (key, value) in mods {
+            // 従来の `str_` キーは `str` として合算する
+            let key = if key == "str_" { "str" } else { key.as_str() };
+            *total.entry(key).or_insert(0) += value;
+        }
+    }
+    let value = serde_json::to_value(&total).map_err(ApiError::internal)?;
+    let bonus: BonusStats = serde_json::from_value(value).map_err(|e| {
+        ApiError::new(ErrorCode::InvalidInput, format!("Invalid gear: {}", e)).with_field("gear")
+    })?;
+
+    // serde は未知のキーを無視するため、往復させて欠落したキーを検出する
+    let known = serde_json::to_value(&bonus).map_err(ApiError::internal)?;
+    if let Some(key) = total.keys().find(|key| known.get(**key).is_none()) {
+        return Err(ApiError::new(
+            ErrorCode::InvalidInput,
+            format!("Unknown gear stat: {}", key),
+        )
+        .with_field("gear"));
+    }
+    Ok(bonus)
+}
+
+impl CharaInput {
+    fn into_chara(self, bonus_stats: BonusStats) -> Result<Chara, ApiError> {
+        let race = self
+            .race
+            .parse()
+            .map_err(|e| ApiError::new(ErrorCode::InvalidRace, e).with_field("race"))?;
+        let parse_job = |s: &str, field: &str| {
+            s.parse::<Job>()
+                .map_err(|e| ApiError::new(ErrorCode::InvalidJob, e).with_field(field))
+        };
+        let mut builder = Chara::builder()
+            .race(race)
+            .main_job(parse_job(&self.main_job, "main_job")?, self.main_lv)
+            .master_lv(self.master_lv)
+            .merit_points(self.merit_points.into())
+            .bonus_stats(bonus_stats);
+        if let (Some(sj), Some(sl)) = (self.support_job, self.support_lv) {
+            builder = builder.support_job(parse_job(&sj, "support_job")?, sl);
+        }
+        builder.build().map_err(ApiError::from)
+    }
+}
+
+fn calculate_with_gear_native(
+    chara: CharaInput,
+    gear: &GearInput,
+) -> Result<StatusResult, ApiError> {
+    let chara = chara.into_chara(gear_to_bonus_stats(gear)?)?;
+    Ok(chara_to_status_result(&chara))
+}
+
+/// キャラクター指定と各スロットの装備補正から、装備込みのステータスを計算する。
+/// JS: calculate_with_gear({ race: "Hum", main_job: "War", main_lv: 99, ... },
+///                         { head: { str: 33, attack: 70 }, ... }) → StatusResult
+/// gear が空 ({}) の場合は素ステータスと一致する。
 #[wasm_bindgen]
-pub fn calculate_status(
+pub fn calculate_with_gear(chara_js: JsValue, gear_js: JsValue) -> Result<JsValue, JsValue> {
+    let chara: CharaInput = serde_wasm_bindgen::from_value(chara_js).map_err(|e| {
+        ApiError::new(ErrorCode::InvalidInput, format!("Invalid chara: {}", e)).with_field("chara")
+    })?;
+    let gear: GearInput = if gear_js.is_undefined() || gear_js.is_null() {
+        GearInput::new()
+    } else {
+        serde_wasm_bindgen::from_value(gear_js).map_err(|e| {
+            ApiError::new(ErrorCode::InvalidInput, format!("Invalid gear: {}", e))
+                .with_field("gear")
+        })?
+    };
+
+    calculate_with_gear_native(chara, &gear)?
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// calculate_batch の 1 件分の結果。成功時は StatusResult、失敗時は { error: ApiError }
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum BatchResult {
+    Ok(Box<StatusResult>),
+    Err { error: ApiError },
+}
+
+fn calculate_batch_native(requests: Vec<serde_json::Value>) -> Vec<BatchResult> {
+    requests
+        .into_iter()
+        .map(|request| {
+            serde_json::from_value::<CharaInput>(request)
+                .map_err(|e| {
+                    ApiError::new(ErrorCode::InvalidInput, format!("Invalid request: {}", e))
+                })
+                .and_then(|input| input.into_chara(BonusStats::default()))
+                .map(|chara| chara_to_status_result(&chara))
+        })
+        .map(|result| match result {
+            Ok(status) => BatchResult::Ok(Box::new(status)),
+            Err(error) => BatchResult::Err { error },
+        })
+        .collect()
+}
+
+/// 複数構成のステータスをまとめて計算する。1 件の失敗は他の結果に影響しない。
+/// JS: calculate_batch([{ race, main_job, main_lv, support_job, support_lv, master_lv, merit_points }, ...])
+///     → [StatusResult | { error: { code, message, field? } }, ...] (入力と同じ順序)
+#[wasm_bindgen]
+pub fn calculate_batch(requests_js: JsValue) -> Result<JsValue, JsValue> {
+    let requests: Vec<serde_json::Value> =
+        serde_wasm_bindgen::from_value(requests_js).map_err(|e| {
+            ApiError::new(ErrorCode::InvalidInput, format!("Invalid requests: {}", e))
+                .with_field("requests")
+        })?;
+
+    calculate_batch_native(requests)
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// 戦闘派生ステータス (calculate_full 用)
+#[derive(Serialize, Deserialize)]
+pub struct CombatResult {
+    /// メイン攻撃力総合値
+    pub attack: i32,
+    /// 防御力総合値
+    pub defense: i32,
+    /// メイン命中総合値
+    pub accuracy: i32,
+    /// 回避総合値
+    pub evasion: i32,
+}
+
+/// calculate_full の戻り値: { status, combat }
+#[derive(Serialize, Deserialize)]
+pub struct FullResult {
+    pub status: StatusResult,
+    pub combat: CombatResult,
+}
+
+fn chara_to_full_result(chara: &Chara) -> FullResult {
+    let status = chara_to_status_result(chara);
+    let combat = CombatResult {
+        attack: status.main_attack,
+        defense: status.def,
+        accuracy: status.main_accuracy,
+        evasion: status.evasion,
+    };
+    FullResult { status, combat }
+}
+
+/// calculate_status / calculate_full 共通の引数から Chara を構築する
+#[allow(clippy::too_many_arguments)]
+fn build_chara_from_args(
     race: &str,
     main_job: &str,
     main_lv: i32,
@@ -444,23 +609,37 @@ pub fn calculate_status(
     master_lv: i32,
     merit_points_js: JsValue,
     bonus_stats_js: JsValue,
-) -> Result<JsValue, JsValue> {
-    let race = str_to_race(race).ok_or_else(|| JsValue::from_str("Invalid race"))?;
-    let main_job = str_to_job(main_job).ok_or_else(|| JsValue::from_str("Invalid main job"))?;
+) -> Result<Chara, ApiError> {
+    let race = str_to_race(race)
+        .ok_or_else(|| ApiError::new(ErrorCode::InvalidRace, "Invalid race").with_field("race"))?;
+    let main_job = main_job.parse::<Job>().map_err(|_| {
+        ApiError::new(ErrorCode::InvalidJob, "Invalid main job").with_field("main_job")
+    })?;
 
     let merit_points: MeritPoints = if merit_points_js.is_undefined() || merit_points_js.is_null() {
         MeritPoints::default()
     } else {
-        let input: MeritPointsInput = serde_wasm_bindgen::from_value(merit_points_js)
-            .map_err(|e| JsValue::from_str(&format!("Invalid merit points: {}", e)))?;
+        let input: MeritPointsInput =
+            serde_wasm_bindgen::from_value(merit_points_js).map_err(|e| {
+                ApiError::new(
+                    ErrorCode::InvalidInput,
+                    format!("Invalid merit points: {}", e),
+                )
+                .with_field("merit_points")
+            })?;
         input.into()
     };
 
     let bonus_stats: BonusStats = if bonus_stats_js.is_undefined() || bonus_stats_js.is_null() {
         BonusStats::default()
     } else {
-        serde_wasm_bindgen::from_value(bonus_stats_js)
-            .map_err(|e| JsValue::from_str(&format!("Invalid bonus stats: {}", e)))?
+        serde_wasm_bindgen::from_value(bonus_stats_js).map_err(|e| {
+            ApiError::new(
+                ErrorCode::InvalidInput,
+                format!("Invalid bonus stats: {}", e),
+            )
+            .with_field("bonus_stats")
+        })?
     };
 
     let mut builder = Chara::builder()
@@ -471,18 +650,105 @@ pub fn calculate_status(
         .bonus_stats(bonus_stats);
 
     if let (Some(sj), Some(sl)) = (support_job, support_lv) {
-        let support_job = str_to_job(&sj).ok_or_else(|| JsValue::from_str("Invalid support job"))?;
+        let support_job = sj.parse::<Job>().map_err(|_| {
+            ApiError::new(ErrorCode::InvalidJob, "Invalid support job").with_field("support_job")
+        })?;
         builder = builder.support_job(support_job, sl);
     }
 
-    let chara = builder
+    builder.build().map_err(ApiError::from)
+}
+
+/// 入力不正時のフォールバック結果 (Hum/War99 ML0・サポートなし・メリット/装備なし) に警告を付ける
+fn default_status_result(error: &str) -> StatusResult {
+    let chara = Chara::builder()
+        .race(Race::Hum)
+        .main_job(Job::War, 99)
+        .master_lv(0)
         .build()
-        .map_err(|e| JsValue::from_str(e))?;
+        .expect("default chara is always valid");
+    StatusResult {
+        warning: Some(format!(
+            "Invalid input, using default (Hum/War99): {}",
+            error
+        )),
+        ..chara_to_status_result(&chara)
+    }
+}
 
-    let result = chara_to_status_result(&chara);
+/// 構築結果を StatusResult にする。default_on_error が true なら、
+/// 構築エラーをデフォルト構成の結果 (警告付き) に置き換える
+fn status_or_default(
+    chara: Result<Chara, ApiError>,
+    default_on_error: bool,
+) -> Result<StatusResult, ApiError> {
+    match chara {
+        Ok(chara) => Ok(chara_to_status_result(&chara)),
+        Err(e) if default_on_error => Ok(default_status_result(&e.message)),
+        Err(e) => Err(e),
+    }
+}
+
+/// default_on_error が true の場合、入力が不正でもエラーにせず
+/// デフォルト構成 (Hum/War99) の結果を `warning` 付きで返す (UI 初期状態向け)。
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_status(
+    race: &str,
+    main_job: &str,
+    main_lv: i32,
+    support_job: Option<String>,
+    support_lv: Option<i32>,
+    master_lv: i32,
+    merit_points_js: JsValue,
+    bonus_stats_js: JsValue,
+    default_on_error: Option<bool>,
+) -> Result<JsValue, JsValue> {
+    let chara = build_chara_from_args(
+        race,
+        main_job,
+        main_lv,
+        support_job,
+        support_lv,
+        master_lv,
+        merit_points_js,
+        bonus_stats_js,
+    );
+
+    status_or_default(chara, default_on_error.unwrap_or(false))?
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// calculate_status と同じ引数で、ステータスに加えて戦闘派生ステータスも返す。
+/// JS: calculate_full(...) → { status: {...}, combat: { attack, defense, accuracy, evasion } }
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_full(
+    race: &str,
+    main_job: &str,
+    main_lv: i32,
+    support_job: Option<String>,
+    support_lv: Option<i32>,
+    master_lv: i32,
+    merit_points_js: JsValue,
+    bonus_stats_js: JsValue,
+) -> Result<JsValue, JsValue> {
+    let chara = build_chara_from_args(
+        race,
+        main_job,
+        main_lv,
+        support_job,
+        support_lv,
+        master_lv,
+        merit_points_js,
+        bonus_stats_js,
+    )?;
+
+    let result = chara_to_full_result(&chara);
     result
         .serialize(&object_serializer())
-        .map_err(|e| JsValue::from_str(&e.to_string()))
+        .map_err(|e| ApiError::internal(e).into())
 }
 
 #[wasm_bindgen]
@@ -524,6 +790,54 @@ pub fn get_jobs() -> Vec<JsValue> {
     ]
 }
 
+/// ジョブ・種族の表示名 (`get_jobs_detailed` / `get_races_detailed` の要素)
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct NameEntry {
+    pub id: String,
+    pub abbr: String,
+    pub name_ja: String,
+}
+
+fn job_name_entries() -> Vec<NameEntry> {
+    Job::VARIANTS
+        .iter()
+        .map(|job| NameEntry {
+            id: format!("{:?}", job),
+            abbr: format!("{:?}", job),
+            name_ja: job.name_ja().to_string(),
+        })
+        .collect()
+}
+
+fn race_name_entries() -> Vec<NameEntry> {
+    Race::VARIANTS
+        .iter()
+        .map(|race| NameEntry {
+            id: format!("{:?}", race),
+            abbr: format!("{:?}", race),
+            name_ja: race.name_ja().to_string(),
+        })
+        .collect()
+}
+
+/// 全ジョブの表示名一覧。
+/// JS: get_jobs_detailed() → [{ id: "War", abbr: "War", name_ja: "戦士" }, ...]
+#[wasm_bindgen]
+pub fn get_jobs_detailed() -> Result<JsValue, JsValue> {
+    job_name_entries()
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// 全種族の表示名一覧。
+/// JS: get_races_detailed() → [{ id: "Hum", abbr: "Hum", name_ja: "ヒューム" }, ...]
+#[wasm_bindgen]
+pub fn get_races_detailed() -> Result<JsValue, JsValue> {
+    race_name_entries()
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
+}
+
 /// SkillKind を JSON キー用の文字列（Pascal ケース）に変換する。
 fn skill_kind_to_key(kind: SkillKind) -> &'static str {
     kind.key()
@@ -556,23 +870,21 @@ fn skill_gift_bonus(job: crate::job::Job, skill: SkillKind, total_jp: i32) -> i3
 
 fn chara_to_status_result(chara: &Chara) -> StatusResult {
     use crate::status::{
-        calc_accuracy, calc_defense, calc_evasion, calc_magic_attack, calc_magic_defense,
-        calc_main_attack, calc_ranged_accuracy, calc_ranged_attack, calc_sub_attack,
+        calc_accuracy, calc_defense, calc_evasion, calc_magic_defense, calc_main_attack,
+        calc_ranged_accuracy, calc_ranged_attack, calc_sub_attack,
     };
-    let vit = chara.status(StatusKind::Vit);
-    let agi = chara.status(StatusKind::Agi);
-    let str_val = chara.status(StatusKind::Str);
-    let dex = chara.status(StatusKind::Dex);
+    let status = chara.to_status();
+    let vit = status[StatusKind::Vit];
+    let agi = status[StatusKind::Agi];
+    let str_val = status[StatusKind::Str];
+    let dex = status[StatusKind::Dex];
     let defense_bonus_trait = chara.job_trait_total(JobTrait::DefenseBonus);
     let mdef_trait = chara.job_trait_total(JobTrait::MagicDefenseBonus);
     let attack_bonus_trait = chara.job_trait_total(JobTrait::AttackBonus);
     let evasion_bonus_trait = chara.job_trait_total(JobTrait::EvasionBonus);
     let accuracy_bonus_trait = chara.job_trait_total(JobTrait::AccuracyBonus);
-    let magic_attack_bonus_trait = chara.job_trait_total(JobTrait::MagicAttackBonus);
     let magic_accuracy_bonus_trait = chara.job_trait_total(JobTrait::MagicAccuracyBonus);
     let magic_evasion_bonus_trait = chara.job_trait_total(JobTrait::MagicEvasionBonus);
-    let auto_regen_trait = chara.job_trait_total(JobTrait::AutoRegen);
-    let auto_refresh_trait = chara.job_trait_total(JobTrait::AutoRefresh);
     let triple_attack_trait = chara.job_trait_total(JobTrait::TripleAttack);
     let subtle_blow_trait = chara.job_trait_total(JobTrait::SubtleBlow);
     let rapid_shot_trait = chara.job_trait_total(JobTrait::RapidShot);
@@ -607,7 +919,7 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
     // ジョブポイント / ギフトによる戦闘ステータスボーナス
     let total_jp = chara.job_points.total_jp_spent();
     let gift = calc_gift_bonuses(chara.main_job, total_jp);
-    let jp_cat = calc_jp_category_bonuses(chara.main_job, &chara.job_points);
+    let jp_cat = chara.job_point_category_bonuses();
 
     // Store TP メリット (SAM 専用、+1/rank、最大 5)
     let store_tp_merit = if chara.main_job == Job::Sam {
@@ -738,7 +1050,7 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
     let defense_bonus = defense_bonus_trait + gift.physical_defense + jp_cat.physical_defense;
     let evasion_bonus = evasion_bonus_trait + gift.physical_evasion + jp_cat.physical_evasion;
     let accuracy_bonus = accuracy_bonus_trait + gift.physical_accuracy + jp_cat.physical_accuracy;
-    let magic_attack_bonus = magic_attack_bonus_trait + gift.magic_attack + jp_cat.magic_attack;
+    let magic_attack_bonus = chara.magic_attack_bonus();
     let magic_accuracy_bonus =
         magic_accuracy_bonus_trait + gift.magic_accuracy + jp_cat.magic_accuracy;
     let magic_evasion_bonus =
@@ -753,16 +1065,21 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
         + double_attack_merit
         + double_attack_gift;
 
+    // 装備のステータス変換効果 (変換先ごとの加算量)
+    let conversion = |to: ModKind| chara.bonus_stats.conversion_total(to, |k| chara.status(k));
+
     // 総合値の計算
-    let def_total = calc_defense(vit, chara.main_lv, chara.bonus_stats.def) + defense_bonus;
+    let def_total = calc_defense(vit, chara.main_lv, chara.bonus_stats.def)
+        + defense_bonus
+        + conversion(ModKind::Defense);
     let mdef_total = calc_magic_defense(chara.bonus_stats.magic_def_bonus)
         + mdef_trait
         + gift.magic_defense
         + jp_cat.magic_defense;
-    let evasion_total =
-        calc_evasion(agi, eff_evasion_skill, chara.bonus_stats.evasion) + evasion_bonus;
-    let magic_attack_total =
-        calc_magic_attack(chara.bonus_stats.magic_attack) + magic_attack_bonus;
+    let evasion_total = calc_evasion(agi, eff_evasion_skill, chara.bonus_stats.evasion)
+        + evasion_bonus
+        + conversion(ModKind::Evasion);
+    let magic_attack_total = chara.magic_attack();
 
     // メイン攻撃/命中
     // メイン武器未装備時は H2H 扱いで H2H スキル値を使う
@@ -775,22 +1092,25 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
             + global_bonus(SkillKind::HandToHand);
         (h2h_v, true)
     };
-    let main_attack_total = calc_main_attack(
-        str_val,
-        main_skill_value,
-        is_h2h,
-        chara.bonus_stats.attack,
-    ) + attack_bonus;
-    let main_accuracy_total =
-        calc_accuracy(dex, main_skill_value, chara.bonus_stats.accuracy) + accuracy_bonus;
+    let attack_conversion = conversion(ModKind::Attack);
+    let accuracy_conversion = conversion(ModKind::Accuracy);
+    let main_attack_total =
+        calc_main_attack(str_val, main_skill_value, is_h2h, chara.bonus_stats.attack)
+            + attack_bonus
+            + attack_conversion;
+    let main_accuracy_total = calc_accuracy(dex, main_skill_value, chara.bonus_stats.accuracy)
+        + accuracy_bonus
+        + accuracy_conversion;
 
     // サブ攻撃/命中 (サブ武器装備時のみ)
     let (sub_attack_total, sub_accuracy_total) = match sub_weapon {
         Some((_, skill_v)) => {
-            let atk =
-                calc_sub_attack(str_val, skill_v, chara.bonus_stats.attack) + attack_bonus;
-            let acc =
-                calc_accuracy(dex, skill_v, chara.bonus_stats.accuracy) + accuracy_bonus;
+            let atk = calc_sub_attack(str_val, skill_v, chara.bonus_stats.attack)
+                + attack_bonus
+                + attack_conversion;
+            let acc = calc_accuracy(dex, skill_v, chara.bonus_stats.accuracy)
+                + accuracy_bonus
+                + accuracy_conversion;
             (Some(atk), Some(acc))
         }
         None => (None, None),
@@ -804,25 +1124,27 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
         Some((_, skill_v)) => {
             let atk = calc_ranged_attack(str_val, skill_v, chara.bonus_stats.ranged_attack)
                 + attack_bonus
-                + ranged_attack_extra;
+                + ranged_attack_extra
+                + conversion(ModKind::RangedAttack);
             let acc = calc_ranged_accuracy(agi, skill_v, chara.bonus_stats.ranged_accuracy)
                 + accuracy_bonus
-                + ranged_accuracy_extra;
+                + ranged_accuracy_extra
+                + conversion(ModKind::RangedAccuracy);
             (Some(atk), Some(acc))
         }
         None => (None, None),
     };
 
     StatusResult {
-        hp: chara.status(StatusKind::Hp),
-        mp: chara.status(StatusKind::Mp),
+        hp: status[StatusKind::Hp],
+        mp: status[StatusKind::Mp],
         str_: str_val,
         dex,
         vit,
         agi,
-        int: chara.status(StatusKind::Int),
-        mnd: chara.status(StatusKind::Mnd),
-        chr: chara.status(StatusKind::Chr),
+        int: status[StatusKind::Int],
+        mnd: status[StatusKind::Mnd],
+        chr: status[StatusKind::Chr],
         def: def_total,
         mdef: mdef_total,
         evasion: evasion_total,
@@ -851,8 +1173,8 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
             + triple_attack_trait
             + chara.main_job.gift_value(Gift::TripleAttackRate, total_jp),
         // オートリジェネ/リフレシュ 総合 = 装備 + ジョブ特性
-        regen: chara.bonus_stats.regen + auto_regen_trait,
-        refresh: chara.bonus_stats.refresh + auto_refresh_trait,
+        regen: chara.regen_total(),
+        refresh: chara.refresh_total(),
         // モクシャ 総合 = 装備 + ジョブ特性 + ギフト (Mnk/Dnc)
         subtle_blow: chara.bonus_stats.subtle_blow
             + subtle_blow_trait
@@ -1036,6 +1358,7 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
         sub_weapon_skill_value,
         ranged_weapon_skill,
         ranged_weapon_skill_value,
+        warning: None,
     }
 }
 
@@ -1043,9 +1366,11 @@ fn chara_to_status_result(chara: &Chara) -> StatusResult {
 /// JS: calculate_default_skills(profile) → { HandToHand: 0, ..., Handbell: 0 }
 #[wasm_bindgen]
 pub fn calculate_default_skills(profile_js: JsValue) -> Result<JsValue, JsValue> {
-    let profile: CharacterProfile = serde_wasm_bindgen::from_value(profile_js)
-        .map_err(|e| JsValue::from_str(&format!("Invalid profile: {}", e)))?;
-    let skills = default_skills(&profile.job_levels, &profile.merit_points);
+    let profile: CharacterProfile = serde_wasm_bindgen::from_value(profile_js).map_err(|e| {
+        ApiError::new(ErrorCode::InvalidInput, format!("Invalid profile: {}", e))
+            .with_field("profile")
+    })?;
+    let skills = default_skills(profile.job_levels(), &profile.merit_points);
     let mut map: BTreeMap<String, i32> = BTreeMap::new();
     for skill in <SkillKind as VariantArray>::VARIANTS {
         map.insert(
@@ -1054,7 +1379,69 @@ pub fn calculate_default_skills(profile_js: JsValue) -> Result<JsValue, JsValue>
         );
     }
     map.serialize(&object_serializer())
-        .map_err(|e| JsValue::from_str(&e.to_string()))
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// ジョブ・レベルにおける全スキルの上限 (マスターレベル 0)。ジョブが持たないスキルは 0。
+fn skill_caps_native(job: &str, lv: i32) -> Result<BTreeMap<String, i32>, ApiError> {
+    let job = job
+        .parse::<Job>()
+        .map_err(|e| ApiError::new(ErrorCode::InvalidJob, e).with_field("job"))?;
+    if !(1..=99).contains(&lv) {
+        return Err(
+            ApiError::new(ErrorCode::OutOfRange, "lv must be between 1 and 99").with_field("lv"),
+        );
+    }
+    Ok(<SkillKind as VariantArray>::VARIANTS
+        .iter()
+        .map(|&skill| {
+            (
+                skill_kind_to_key(skill).to_string(),
+                job.skill_cap(skill, lv),
+            )
+        })
+        .collect())
+}
+
+/// ジョブのスキル上限一覧。
+/// JS: get_skill_caps("War", 99) → { HandToHand: ..., Sword: ..., Elemental: 0, ... }
+#[wasm_bindgen]
+pub fn get_skill_caps(job: &str, lv: i32) -> Result<JsValue, JsValue> {
+    skill_caps_native(job, lv)?
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// validate_profile の戻り値: { valid, errors }
+#[derive(Debug, Serialize)]
+pub struct ProfileValidation {
+    pub valid: bool,
+    pub errors: Vec<String>,
+}
+
+fn validate_profile_native(profile: serde_json::Value) -> ProfileValidation {
+    let errors = match serde_json::from_value::<CharacterProfile>(profile) {
+        Ok(profile) => profile.validate(),
+        Err(e) => vec![format!("Invalid profile: {}", e)],
+    };
+    ProfileValidation {
+        valid: errors.is_empty(),
+        errors,
+    }
+}
+
+/// calculate_status_from_profile に渡す前にプロファイルを検証する。
+/// JS: validate_profile(profile) → { valid: boolean, errors: string[] }
+/// 形式が不正な場合もエラーにはせず、`errors` にデシリアライズ失敗の内容を入れて返す。
+#[wasm_bindgen]
+pub fn validate_profile(profile_js: JsValue) -> Result<JsValue, JsValue> {
+    let profile: serde_json::Value = serde_wasm_bindgen::from_value(profile_js).map_err(|e| {
+        ApiError::new(ErrorCode::InvalidInput, format!("Invalid profile: {}", e))
+            .with_field("profile")
+    })?;
+    validate_profile_native(profile)
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
 }
 
 /// CharacterProfile の JSON データからステータスを計算する。
@@ -1068,35 +1455,104 @@ pub fn calculate_status_from_profile(
     support_job: Option<String>,
     bonus_stats_js: JsValue,
 ) -> Result<JsValue, JsValue> {
-    let profile: CharacterProfile = serde_wasm_bindgen::from_value(profile_js)
-        .map_err(|e| JsValue::from_str(&format!("Invalid profile: {}", e)))?;
+    let profile: CharacterProfile = serde_wasm_bindgen::from_value(profile_js).map_err(|e| {
+        ApiError::new(ErrorCode::InvalidInput, format!("Invalid profile: {}", e))
+            .with_field("profile")
+    })?;
 
-    let main_job = str_to_job(main_job)
-        .ok_or_else(|| JsValue::from_str("Invalid main job"))?;
+    let main_job = main_job.parse::<Job>().map_err(|_| {
+        ApiError::new(ErrorCode::InvalidJob, "Invalid main job").with_field("main_job")
+    })?;
 
     let support_job = match support_job {
-        Some(ref sj) => Some(
-            str_to_job(sj).ok_or_else(|| JsValue::from_str("Invalid support job"))?,
-        ),
+        Some(ref sj) => Some(sj.parse::<Job>().map_err(|_| {
+            ApiError::new(ErrorCode::InvalidJob, "Invalid support job").with_field("support_job")
+        })?),
         None => None,
     };
 
     let bonus_stats: BonusStats = if bonus_stats_js.is_undefined() || bonus_stats_js.is_null() {
         BonusStats::default()
     } else {
-        serde_wasm_bindgen::from_value(bonus_stats_js)
-            .map_err(|e| JsValue::from_str(&format!("Invalid bonus stats: {}", e)))?
+        serde_wasm_bindgen::from_value(bonus_stats_js).map_err(|e| {
+            ApiError::new(
+                ErrorCode::InvalidInput,
+                format!("Invalid bonus stats: {}", e),
+            )
+            .with_field("bonus_stats")
+        })?
     };
 
     let mut chara = profile
         .to_chara(main_job, support_job)
-        .map_err(|e| JsValue::from_str(&e))?;
+        .map_err(ApiError::from)?;
     chara.bonus_stats = bonus_stats;
 
     let result = chara_to_status_result(&chara);
     result
         .serialize(&object_serializer())
-        .map_err(|e| JsValue::from_str(&e.to_string()))
+        .map_err(|e| ApiError::internal(e).into())
+}
+
+/// `compare_two_supports` の結果。`diff` は各ステータスの `a - b`
+#[derive(Serialize)]
+pub struct SupportComparison {
+    pub a: StatusResult,
+    pub b: StatusResult,
+    pub diff: BTreeMap<String, i32>,
+}
+
+fn compare_two_supports_native(
+    profile: &CharacterProfile,
+    main_job: Job,
+    sub_a: Job,
+    sub_b: Job,
+) -> Result<SupportComparison, ApiError> {
+    let to_chara = |sub| {
+        profile
+            .to_chara(main_job, Some(sub))
+            .map_err(ApiError::from)
+    };
+    let chara_a = to_chara(sub_a)?;
+    let chara_b = to_chara(sub_b)?;
+    let diff = chara_a
+        .diff(&chara_b)
+        .into_iter()
+        .map(|(kind, v)| (format!("{:?}", kind).to_lowercase(), v))
+        .collect();
+    Ok(SupportComparison {
+        a: chara_to_status_result(&chara_a),
+        b: chara_to_status_result(&chara_b),
+        diff,
+    })
+}
+
+/// 同じメインジョブで 2 つのサポートジョブ案のステータスと差分を返す。
+/// JS: compare_two_supports(profile, "War", "Sam", "Nin") → { a: {...}, b: {...}, diff: { hp, mp, str, ... } }
+/// どちらかのサポートジョブが未レベルの場合はエラー。
+#[wasm_bindgen]
+pub fn compare_two_supports(
+    profile_js: JsValue,
+    main_job: &str,
+    sub_a: &str,
+    sub_b: &str,
+) -> Result<JsValue, JsValue> {
+    let profile: CharacterProfile = serde_wasm_bindgen::from_value(profile_js).map_err(|e| {
+        ApiError::new(ErrorCode::InvalidInput, format!("Invalid profile: {}", e))
+            .with_field("profile")
+    })?;
+    let parse_job = |s: &str, label: &str, field: &str| {
+        s.parse::<Job>().map_err(|_| {
+            ApiError::new(ErrorCode::InvalidJob, format!("Invalid {}", label)).with_field(field)
+        })
+    };
+    let main_job = parse_job(main_job, "main job", "main_job")?;
+    let sub_a = parse_job(sub_a, "support job", "sub_a")?;
+    let sub_b = parse_job(sub_b, "support job", "sub_b")?;
+
+    compare_two_supports_native(&profile, main_job, sub_a, sub_b)?
+        .serialize(&object_serializer())
+        .map_err(|e| ApiError::internal(e).into())
 }
 
 #[cfg(test)]
@@ -1134,15 +1590,17 @@ mod tests {
     ///   ※ 通常ステータスは race+main_job+support_job を sum→floor 後に
     ///      ML/メリット/ジョブ特性を加算する仕様
     ///
-    ///   STR=161  内訳: メインジョブ(Hum 37.50 + War99 45.00) + サポートジョブ(Sam59 27.00/2=13.50)
-    ///                   = floor(96.00) + マスターレベル(50*1=50) + メリット(15*1=15)  [+ ジョブポイント=0, ギフト=0]
-    ///   DEX=156  内訳: floor(37.50 + 40.50 + 13.50) + 50 + 15
-    ///   VIT=153  内訳: floor(37.50 + 37.50 + 13.50) + 50 + 15
-    ///   AGI=154  内訳: floor(37.50 + 40.50 + 11.50) + 50 + 15
-    ///   INT=143  内訳: floor(37.50 + 31.00 + 10.00) + 50 + 15
-    ///   MND=143  内訳: floor(37.50 + 31.00 + 10.00) + 50 + 15
-    ///   CHR=148  内訳: floor(37.50 + 34.50 + 11.50) + 50 + 15
-    ///   HP=2095  内訳: floor(485 + 675 + 255) + ML(50*7=350) + メリット(15*10=150)
+    ///   ※ サポートジョブはグレードを 1 段階下げて計算する (Sam STR C→D など)
+    ///
+    ///   STR=159  内訳: メインジョブ(Hum 37.50 + War99 45.00) + サポートジョブ(Sam59 D 23.00/2=11.50)
+    ///                   = floor(94.00) + マスターレベル(50*1=50) + メリット(15*1=15)  [+ ジョブポイント=0, ギフト=0]
+    ///   DEX=154  内訳: floor(37.50 + 40.50 + 11.50) + 50 + 15
+    ///   VIT=151  内訳: floor(37.50 + 37.50 + 11.50) + 50 + 15
+    ///   AGI=153  内訳: floor(37.50 + 40.50 + 10.00) + 50 + 15
+    ///   INT=141  内訳: floor(37.50 + 31.00 + 8.25) + 50 + 15
+    ///   MND=141  内訳: floor(37.50 + 31.00 + 8.25) + 50 + 15
+    ///   CHR=147  内訳: floor(37.50 + 34.50 + 10.00) + 50 + 15
+    ///   HP=2065  内訳: floor(485 + 675 + 225.5) + ML(50*7=350) + メリット(15*10=150)
     ///                   + ジョブ特性 MaxHpBoost(War90 rank4=180)
     ///   MP=0     War はメインで MP グレードを持たない（実装上 0 を返す）
     ///
@@ -1158,14 +1616,14 @@ mod tests {
     ///
     ///   最終期待値:
     ///     メイン攻撃力 = STR + 武器スキル + 8 + 装備攻撃 + 戦闘ボーナス(攻撃)
-    ///                  = (161+247) + 788 + 8 + 448 + 125
-    ///                  = 408 + 788 + 8 + 448 + 125 = 1777
+    ///                  = (159+247) + 788 + 8 + 448 + 125
+    ///                  = 406 + 788 + 8 + 448 + 125 = 1775
     ///       ※ STR 247 = 装備合計（222 + アスプロ ALL BP+10 + 戦士の数珠オグメ STR+15）
     ///
     ///     メイン命中 = floor(DEX × 0.75) + accuracy_skill_term(skill) + 装備命中 + 戦闘ボーナス(命中)
-    ///                = floor((156+179) × 0.75) + accuracy_skill_term(788) + 448 + 36
-    ///                = floor(335 × 0.75=251.25) + 709 + 448 + 36
-    ///                = 251 + 709 + 448 + 36 = 1444
+    ///                = floor((154+179) × 0.75) + accuracy_skill_term(788) + 448 + 36
+    ///                = floor(333 × 0.75=249.75) + 709 + 448 + 36
+    ///                = 249 + 709 + 448 + 36 = 1442
     ///       ※ DEX 179 = 装備合計（154 + アスプロ ALL BP+10 + 戦士の数珠オグメ DEX+15）
     ///       ※ accuracy_skill_term(788): skill>600 区分 → 540 + floor((788-600)×0.9=169.2) = 540 + 169 = 709
     ///       ※ 装備命中 448 = 431 + アスプロ ACC+15 + ボイイ補正 +2
@@ -1247,13 +1705,13 @@ mod tests {
         let result = chara_to_status_result(&chara);
 
         assert_eq!(
-            result.main_attack, 1777,
-            "メイン攻撃力: got {} expected 1777",
+            result.main_attack, 1775,
+            "メイン攻撃力: got {} expected 1775",
             result.main_attack
         );
         assert_eq!(
-            result.main_accuracy, 1444,
-            "メイン命中: got {} expected 1444",
+            result.main_accuracy, 1442,
+            "メイン命中: got {} expected 1442",
             result.main_accuracy
         );
     }
@@ -1294,14 +1752,14 @@ mod tests {
     ///     キャラスキル値 490 採用 → base 490 + メインスロット(ラフリア +277) = 767
     ///
     ///   メイン攻撃力 = STR + 武器スキル + 8 + 装備攻撃 + 戦闘ボーナス(攻撃)
-    ///                = (161+293) + 767 + 8 + 494 + 125
-    ///                = 454 + 767 + 8 + 494 + 125 = 1848
+    ///                = (159+293) + 767 + 8 + 494 + 125
+    ///                = 452 + 767 + 8 + 494 + 125 = 1846
     ///       ※ 戦闘ボーナス +125 = 特性35 + ギフト70 + JPカテゴリ idx9(20)
     ///
     ///   メイン命中 = floor(DEX × 0.75) + accuracy_skill_term(skill) + 装備命中 + 戦闘ボーナス(命中)
-    ///              = floor((156+145) × 0.75) + accuracy_skill_term(767) + 348 + 36
-    ///              = floor(301 × 0.75=225.75) + (540 + floor((767-600)×0.9=150.3)) + 348 + 36
-    ///              = 225 + 690 + 348 + 36 = 1299
+    ///              = floor((154+145) × 0.75) + accuracy_skill_term(767) + 348 + 36
+    ///              = floor(299 × 0.75=224.25) + (540 + floor((767-600)×0.9=150.3)) + 348 + 36
+    ///              = 224 + 690 + 348 + 36 = 1298
     #[test]
     fn test_war_ws_set_attack_accuracy() {
         // メリットポイント: ステータス全て 15、全スキル 8
@@ -1382,23 +1840,124 @@ mod tests {
         let result = chara_to_status_result(&chara);
 
         assert_eq!(
-            result.main_attack, 1848,
-            "メイン攻撃力: got {} expected 1848",
+            result.main_attack, 1846,
+            "メイン攻撃力: got {} expected 1846",
             result.main_attack
         );
         assert_eq!(
-            result.main_accuracy, 1299,
-            "メイン命中: got {} expected 1299",
+            result.main_accuracy, 1298,
+            "メイン命中: got {} expected 1298",
             result.main_accuracy
         );
     }
 
+    /// Hum War99 ML0 (装備なし) の戦闘派生ステータスが Rust 側の計算式と一致すること
+    #[test]
+    fn test_full_result_war99_combat() {
+        use crate::status::{calc_accuracy, calc_defense, calc_evasion, calc_main_attack};
+
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .expect("Failed to build Chara");
+        let full = chara_to_full_result(&chara);
+        let status = &full.status;
+        let combat = &full.combat;
+
+        // 武器なし = 格闘扱い
+        let h2h = status.effective_skills["HandToHand"];
+        assert_eq!(
+            combat.attack,
+            calc_main_attack(status.str_, h2h, true, 0) + status.attack_bonus
+        );
+        assert_eq!(
+            combat.defense,
+            calc_defense(status.vit, 99, 0) + status.defense_bonus
+        );
+        assert_eq!(
+            combat.accuracy,
+            calc_accuracy(status.dex, h2h, 0) + status.accuracy_bonus
+        );
+        let evasion_skill = status.effective_skills["Evasion"];
+        assert_eq!(
+            combat.evasion,
+            calc_evasion(status.agi, evasion_skill, 0) + status.evasion_bonus
+        );
+
+        // status 部分は calculate_status と同一
+        let plain = chara_to_status_result(&chara);
+        assert_eq!(status.hp, plain.hp);
+        assert_eq!(combat.attack, plain.main_attack);
+        assert_eq!(combat.defense, plain.def);
+    }
+
+    #[test]
+    fn test_name_entries() {
+        let jobs = job_name_entries();
+        assert_eq!(jobs.len(), Job::VARIANTS.len());
+        for (entry, job) in jobs.iter().zip(Job::VARIANTS) {
+            assert_eq!(entry.id.parse::<Job>(), Ok(*job));
+            assert_eq!(entry.name_ja, job.name_ja());
+        }
+        assert_eq!(
+            jobs[0],
+            NameEntry {
+                id: "War".to_string(),
+                abbr: "War".to_string(),
+                name_ja: "戦士".to_string(),
+            }
+        );
+
+        let races = race_name_entries();
+        assert_eq!(races.len(), Race::VARIANTS.len());
+        for (entry, race) in races.iter().zip(Race::VARIANTS) {
+            assert_eq!(entry.abbr.parse::<Race>(), Ok(*race));
+            assert_eq!(entry.name_ja, race.name_ja());
+        }
+    }
+
+    /// 装備の「STR の 50% を攻撃力に変換」効果で攻撃力が増える
+    #[test]
+    fn test_conversion_str_to_attack() {
+        use crate::status::Conversion;
+
+        let build = |bonus: BonusStats| {
+            Chara::builder()
+                .race(Race::Hum)
+                .main_job(Job::War, 99)
+                .master_lv(0)
+                .bonus_stats(bonus)
+                .build()
+                .expect("Failed to build Chara")
+        };
+        let base = chara_to_status_result(&build(BonusStats::default()));
+        let converted = chara_to_status_result(&build(BonusStats {
+            conversions: vec![Conversion {
+                from: StatusKind::Str,
+                to: ModKind::Attack,
+                ratio: 0.5,
+            }],
+            ..BonusStats::default()
+        }));
+
+        // Hum War99 STR 82 → +41
+        assert_eq!(base.str_, 82);
+        assert_eq!(converted.main_attack, base.main_attack + 41);
+        // 変換先以外は変化しない
+        assert_eq!(converted.main_accuracy, base.main_accuracy);
+        assert_eq!(converted.def, base.def);
+    }
+
     /// SAM Lv99 + Store TP メリット 5 + 装備 Store TP+30 のケース。
     /// ジョブ特性 Store TP V (Lv90)=+30, メリット +5, 装備 +30 → 合計 +65
     #[test]
     fn test_sam_store_tp_total() {
-        let mut merit = MeritPoints::default();
-        merit.store_tp = 5;
+        let merit = MeritPoints {
+            store_tp: 5,
+            ..Default::default()
+        };
         let bonus = BonusStats {
             store_tp: 30,
             ..BonusStats::default()
@@ -1423,8 +1982,11 @@ mod tests {
     /// 装備 +20 のみ反映され、トレイト/メリットは 0。
     #[test]
     fn test_war_store_tp_no_trait() {
-        let mut merit = MeritPoints::default();
-        merit.store_tp = 5; // WAR には適用されない
+        // WAR には適用されない
+        let merit = MeritPoints {
+            store_tp: 5,
+            ..Default::default()
+        };
         let bonus = BonusStats {
             store_tp: 20,
             ..BonusStats::default()
@@ -1482,16 +2044,9 @@ mod tests {
     /// 装備・JP なしで SAM99 → 16 (特性のみ)
     #[test]
     fn test_sam99_skillchain_via_profile() {
-        use crate::character_profile::{CharacterProfile, JobLevel};
-        let mut profile = CharacterProfile {
-            name: "Test".to_string(),
-            race: Race::Hum,
-            job_levels: enum_map::enum_map! { _ => JobLevel { level: 0, master_lv: 0 } },
-            merit_points: MeritPoints::default(),
-            job_points: crate::job_points::JobPoints::default(),
-            skills: CharacterSkills::default(),
-        };
-        profile.job_levels[Job::Sam] = JobLevel { level: 99, master_lv: 0 };
+        use crate::character_profile::CharacterProfile;
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_job_level(Job::Sam, 99, 0);
 
         let chara = profile.to_chara(Job::Sam, None).unwrap();
         let result = chara_to_status_result(&chara);
@@ -1502,6 +2057,224 @@ mod tests {
         );
     }
 
+    fn war99_input() -> CharaInput {
+        CharaInput {
+            race: "Hum".to_string(),
+            main_job: "War".to_string(),
+            main_lv: 99,
+            support_job: Some("Sam".to_string()),
+            support_lv: Some(59),
+            master_lv: 50,
+            merit_points: MeritPointsInput::default(),
+        }
+    }
+
+    #[test]
+    fn test_calculate_with_gear_empty_matches_naked() {
+        let result = calculate_with_gear_native(war99_input(), &GearInput::new()).unwrap();
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .support_job(Job::Sam, 59)
+            .master_lv(50)
+            .build()
+            .unwrap();
+        let naked = chara_to_status_result(&chara);
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::to_value(&naked).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_calculate_with_gear_sums_slots() {
+        let naked = calculate_with_gear_native(war99_input(), &GearInput::new()).unwrap();
+        let gear: GearInput = serde_json::from_value(serde_json::json!({
+            "head": { "str": 33, "attack": 70 },
+            // 従来の `str_` キーも同じ STR として合算される
+            "body": { "str_": 43, "hp": 100 },
+        }))
+        .unwrap();
+        let result = calculate_with_gear_native(war99_input(), &gear).unwrap();
+        assert_eq!(result.str_, naked.str_ + 76);
+        assert_eq!(result.hp, naked.hp + 100);
+        let bonus = gear_to_bonus_stats(&gear).unwrap();
+        assert_eq!(serde_json::to_value(&bonus).unwrap()["str"], 76);
+        // 装備攻撃 +70 に加えて STR 増加分も攻撃に乗る
+        assert!(result.main_attack > naked.main_attack + 70);
+
+        let unknown: GearInput =
+            serde_json::from_value(serde_json::json!({ "head": { "luck": 1 } })).unwrap();
+        let err = calculate_with_gear_native(war99_input(), &unknown)
+            .err()
+            .expect("unknown key is an error");
+        assert_eq!(err.code, ErrorCode::InvalidInput);
+        assert_eq!(err.field.as_deref(), Some("gear"));
+        assert!(err.message.contains("Unknown gear stat: luck"), "{}", err);
+    }
+
+    #[test]
+    fn test_chara_input_errors_are_structured() {
+        let err = |input: CharaInput| {
+            input
+                .into_chara(BonusStats::default())
+                .expect_err("invalid input")
+        };
+
+        let e = err(CharaInput {
+            race: "Xyz".to_string(),
+            ..war99_input()
+        });
+        assert_eq!(e.code, ErrorCode::InvalidRace);
+        assert_eq!(e.field.as_deref(), Some("race"));
+        assert_eq!(e.message, "Unknown race: Xyz");
+
+        let e = err(CharaInput {
+            support_job: Some("Xyz".to_string()),
+            ..war99_input()
+        });
+        assert_eq!(e.code, ErrorCode::InvalidJob);
+        assert_eq!(e.field.as_deref(), Some("support_job"));
+
+        let e = err(CharaInput {
+            main_lv: 100,
+            ..war99_input()
+        });
+        assert_eq!(e.code, ErrorCode::OutOfRange);
+        assert_eq!(e.field.as_deref(), Some("main_lv"));
+        assert_eq!(e.message, "main_lv must be between 1 and 99");
+
+        let e = err(CharaInput {
+            master_lv: 51,
+            ..war99_input()
+        });
+        assert_eq!(e.code, ErrorCode::OutOfRange);
+        assert_eq!(e.field.as_deref(), Some("master_lv"));
+    }
+
+    #[test]
+    fn test_calculate_batch_empty() {
+        assert!(calculate_batch_native(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_calculate_batch_partial_failure() {
+        let requests = vec![
+            serde_json::json!({ "race": "Hum", "main_job": "War", "main_lv": 99 }),
+            serde_json::json!({ "race": "Hum", "main_job": "Xyz", "main_lv": 99 }),
+            serde_json::json!({ "race": "Hum", "main_job": "War" }),
+            serde_json::json!({
+                "race": "Hum", "main_job": "Blm", "main_lv": 99,
+                "support_job": "Whm", "support_lv": 49, "master_lv": 0,
+                "merit_points": { "mp": 5 },
+            }),
+            serde_json::json!({ "race": "Hum", "main_job": "War", "main_lv": 100 }),
+        ];
+        let results = calculate_batch_native(requests);
+        assert_eq!(results.len(), 5);
+        assert!(matches!(&results[0], BatchResult::Ok(r) if r.hp == 1340));
+        assert!(matches!(
+            &results[1],
+            BatchResult::Err { error }
+                if error.code == ErrorCode::InvalidJob && error.message.contains("Unknown job")
+        ));
+        assert!(matches!(
+            &results[2],
+            BatchResult::Err { error }
+                if error.code == ErrorCode::InvalidInput && error.message.contains("main_lv")
+        ));
+        assert!(matches!(&results[3], BatchResult::Ok(r) if r.mp > 1160));
+
+        // エラーは { error: { code, message, field } } のオブジェクトとしてシリアライズされる
+        let json = serde_json::to_value(&results).unwrap();
+        // build() の範囲外エラーも code / field を保持する
+        assert_eq!(json[4]["error"]["code"], "OUT_OF_RANGE");
+        assert_eq!(json[4]["error"]["field"], "main_lv");
+        assert!(json[1]["error"]["message"].is_string());
+        assert_eq!(json[0]["hp"], 1340);
+    }
+
+    #[test]
+    fn test_default_status_result() {
+        let result = default_status_result("Invalid main job");
+        assert_eq!(result.hp, 1340);
+        assert_eq!(result.str_, 82);
+        assert!(result.warning.unwrap().contains("Invalid main job"));
+
+        // 通常の結果には warning が付かない
+        let chara = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .unwrap();
+        assert_eq!(chara_to_status_result(&chara).warning, None);
+    }
+
+    #[test]
+    fn test_status_or_default() {
+        // 範囲外の main_lv は build() でエラーになる
+        let invalid = || {
+            Chara::builder()
+                .race(Race::Elv)
+                .main_job(Job::Blm, 100)
+                .master_lv(0)
+                .build()
+                .map_err(ApiError::from)
+        };
+
+        // default_on_error: true → Hum/War99 の結果 + 警告
+        let result = status_or_default(invalid(), true).unwrap();
+        assert_eq!(result.hp, 1340);
+        assert!(result.warning.unwrap().contains("main_lv"));
+
+        // default_on_error: false → 元のエラーをそのまま返す
+        let Err(err) = status_or_default(invalid(), false) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.code, ErrorCode::OutOfRange);
+        assert_eq!(err.field.as_deref(), Some("main_lv"));
+
+        // 正常な入力は default_on_error に関わらず警告なし
+        let valid = Chara::builder()
+            .race(Race::Hum)
+            .main_job(Job::War, 99)
+            .master_lv(0)
+            .build()
+            .map_err(ApiError::from);
+        assert_eq!(status_or_default(valid, true).unwrap().warning, None);
+    }
+
+    #[test]
+    fn test_compare_two_supports() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        profile.set_job_level(Job::Sam, 99, 0);
+        profile.set_job_level(Job::Nin, 99, 0);
+
+        let cmp = compare_two_supports_native(&profile, Job::War, Job::Sam, Job::Nin).unwrap();
+        let sam = profile.to_chara(Job::War, Some(Job::Sam)).unwrap();
+        let nin = profile.to_chara(Job::War, Some(Job::Nin)).unwrap();
+        assert_eq!(cmp.a.hp, sam.status(StatusKind::Hp));
+        assert_eq!(cmp.b.hp, nin.status(StatusKind::Hp));
+        assert_eq!(cmp.diff.len(), 9);
+        assert_eq!(cmp.diff["hp"], cmp.a.hp - cmp.b.hp);
+        assert_eq!(cmp.diff["str"], cmp.a.str_ - cmp.b.str_);
+        assert_eq!(cmp.diff["agi"], cmp.a.agi - cmp.b.agi);
+
+        // 片方が未レベルならエラー
+        let err = compare_two_supports_native(&profile, Job::War, Job::Sam, Job::Drg)
+            .err()
+            .expect("Drg is not leveled");
+        assert_eq!(err.code, ErrorCode::JobNotLeveled);
+        assert_eq!(err.field.as_deref(), Some("support_job"));
+        assert!(err.message.contains("not leveled"), "{}", err);
+        let err = compare_two_supports_native(&profile, Job::War, Job::Thf, Job::Nin)
+            .err()
+            .expect("Thf is not leveled");
+        assert!(err.message.contains("not leveled"), "{}", err);
+    }
+
     /// SMN90 → AutoRefresh rank 2 = +2/3sec が StatusResult.refresh に反映
     /// PLD35 → rank 1 = +1
     #[test]
@@ -1839,20 +2612,21 @@ mod tests {
     ///   skill_bonus_global = { Parrying: 511 }
     ///
     /// 内訳（メリットあり: ステータス全 +15、戦闘/魔法スキルメリット 8、JP全カテゴリmax 2100JP）:
-    ///   STR=338 = floor(Hum(D) 37.5 + COR99(E) 34.5 + NIN59/2(C) 13.5)=85 + ML50(50) + Merit(15) + 装備(188)
-    ///   AGI=378 = floor(Hum(D) 37.5 + COR99(B) 49.5 + NIN59/2(B) 15.5)=102 + ML50(50) + Merit(15) + 装備(218)
-    ///                ※ 既存のCOR99 grade テーブル: STR=E, AGI=B / NIN STR=C, AGI=B
+    ///   STR=336 = floor(Hum(D) 37.5 + COR99(E) 34.5 + NIN59/2(D) 11.5)=83 + ML50(50) + Merit(15) + 装備(188)
+    ///   AGI=376 = floor(Hum(D) 37.5 + COR99(B) 42.5 + NIN59/2(C) 13.5)=93 + ML50(50) + Merit(15) + 装備(218)
+    ///                ※ サポートジョブはグレードを 1 段階下げて計算 (NIN STR C→D, AGI B→C)
+    ///                ※ COR99 AGI(B) は 42.5。旧コメントの 49.5 は誤記 (旧期待値 378 も 42.5 + NIN(B) 15.5 で算出)
     ///   ranged_skill_value = COR99 ML50 Marksmanship(B) cap(448) + メリット(8*2=16) + 装備(269) = 733
     ///   attack_bonus  = trait(0, COR/NINに無し) + gift(2100JP→COR slot1=+36) + jp_cat(物理攻撃力 0) = 36
     ///   accuracy_bonus = trait(0) + gift(2100JP→COR slot3=+36) + jp_cat(0) = 36
     ///   ranged_accuracy_extra (COR JP idx 7「遠隔命中アップ」+1/rank × 20) = 20
     ///   ※ COR JP idx 9「適正距離の遠隔攻撃力アップ」は条件付き（適正距離）のためステータスには加算しない
     ///   ranged_attack  = STR + skill + 8 + equip_ranged_attack + attack_bonus
-    ///                  = 338 + 733 + 8 + 423 + 36 = 1538
+    ///                  = 336 + 733 + 8 + 423 + 36 = 1536
     ///   ranged_accuracy = floor(AGI * 0.75) + ranged_accuracy_skill_term(skill) + equip_ranged_accuracy
     ///                     + accuracy_bonus + ranged_accuracy_extra
-    ///                   = floor(378*0.75)=283 + (200 + floor((733-200)*0.9)=200+479=679) + 460 + 36 + 20
-    ///                   = 283 + 679 + 460 + 36 + 20 = 1478
+    ///                   = floor(376*0.75)=282 + (200 + floor((733-200)*0.9)=200+479=679) + 460 + 36 + 20
+    ///                   = 282 + 679 + 460 + 36 + 20 = 1477
     ///
     /// 期待値の枠組み（実装値を観測してから埋める）:
     ///   ・ranged_attack_total = STR + Marksmanship_skill_value + 8 + equip_ranged_attack + attack_bonus
@@ -1956,18 +2730,18 @@ mod tests {
         //   ・有効武器スキル値が違う？（COR99 ML50 cap が違う？合算ロジックの誤り？）
         //   ・遠隔命中の AGI 係数 (現行 0.5)、または skill_term 区分が違う？
         //   ・JP/メリット/ジョブポイントが反映されるべき？
-        assert_eq!(result.str_, 338, "STR mismatch");
-        assert_eq!(result.dex, 321, "DEX mismatch");
-        assert_eq!(result.vit, 314, "VIT mismatch");
-        assert_eq!(result.agi, 378, "AGI mismatch");
-        assert_eq!(result.int, 308, "INT mismatch");
+        assert_eq!(result.str_, 336, "STR mismatch");
+        assert_eq!(result.dex, 319, "DEX mismatch");
+        assert_eq!(result.vit, 312, "VIT mismatch");
+        assert_eq!(result.agi, 376, "AGI mismatch");
+        assert_eq!(result.int, 307, "INT mismatch");
         assert_eq!(result.mnd, 306, "MND mismatch");
-        assert_eq!(result.chr, 293, "CHR mismatch");
+        assert_eq!(result.chr, 291, "CHR mismatch");
         assert_eq!(result.ranged_weapon_skill_value, Some(733), "ranged skill value mismatch");
         assert_eq!(result.attack_bonus, 36, "attack_bonus mismatch (gift COR slot1)");
         assert_eq!(result.accuracy_bonus, 36, "accuracy_bonus mismatch (gift COR slot3)");
-        assert_eq!(result.ranged_attack, Some(1538), "ranged_attack mismatch");
-        assert_eq!(result.ranged_accuracy, Some(1478), "ranged_accuracy mismatch (incl COR JP idx7 +20)");
+        assert_eq!(result.ranged_attack, Some(1536), "ranged_attack mismatch");
+        assert_eq!(result.ranged_accuracy, Some(1477), "ranged_accuracy mismatch (incl COR JP idx7 +20)");
     }
 
     /// 属性WS 用のテストケース。COR99/NIN59 ML50 ML50、メリット全 +15、JP 全カテゴリ最大。
@@ -2085,15 +2859,15 @@ mod tests {
 
         // === 期待値: 既存 COR99/NIN59 ML50 + merit ALL+15 のベース値に装備合算を加算 ===
         // ベース (装備なし) は test_cor_ranged_ws_attack_accuracy で確認済み:
-        //   STR=150 DEX=158 VIT=150 AGI=160 INT=154 MND=143 CHR=145
+        //   STR=148 DEX=156 VIT=148 AGI=158 INT=153 MND=143 CHR=143
         // 本テストの装備合算: STR+159 DEX+130 VIT+145 AGI+193 INT+175 MND+142 CHR+121
-        assert_eq!(result.str_, 309, "STR mismatch (base 150 + equip 159)");
-        assert_eq!(result.dex, 288, "DEX mismatch (base 158 + equip 130)");
-        assert_eq!(result.vit, 295, "VIT mismatch (base 150 + equip 145)");
-        assert_eq!(result.agi, 353, "AGI mismatch (base 160 + equip 193)");
-        assert_eq!(result.int, 329, "INT mismatch (base 154 + equip 175)");
+        assert_eq!(result.str_, 307, "STR mismatch (base 148 + equip 159)");
+        assert_eq!(result.dex, 286, "DEX mismatch (base 156 + equip 130)");
+        assert_eq!(result.vit, 293, "VIT mismatch (base 148 + equip 145)");
+        assert_eq!(result.agi, 351, "AGI mismatch (base 158 + equip 193)");
+        assert_eq!(result.int, 328, "INT mismatch (base 153 + equip 175)");
         assert_eq!(result.mnd, 285, "MND mismatch (base 143 + equip 142)");
-        assert_eq!(result.chr, 266, "CHR mismatch (base 145 + equip 121)");
+        assert_eq!(result.chr, 264, "CHR mismatch (base 143 + equip 121)");
 
         // 主武器 = 短剣 (Dagger)、有効スキル値 = 短剣スキル cap (COR99 ML50) + 装備 269 + 全体 0 + メリット 8
         // CORの短剣スキル cap は default_skills(...) 経由で取得する。
@@ -2160,12 +2934,13 @@ mod tests {
     ///   回避スキル+ (装備直接加算): 0 (該当無し)
     ///
     /// 期待値式 (回避):
-    ///   AGI 総合 = 種族(Hum D) + 主ジョブ(Brd F) + サポ(Pld G)/2 + ML50 + メリット15 + 装備177 = 317
+    ///   AGI 総合 = 種族(Hum D) + 主ジョブ(Brd F) + サポ(Pld G、G は据え置き)/2 + ML50 + メリット15 + 装備177 = 317
     ///   回避スキル有効値 = Brd99 D cap (334) + ML50 + メリット16 = 400
     ///   skill_term = piecewise(400) = 200 + (400-200)*0.9 = 380
     ///   evasion_total = floor(317*0.5) + 380 + 677 + ギフト「物理回避アップ」(Brd 2100JP=22)
     ///                 = 158 + 380 + 677 + 22 = 1237
     #[test]
+    #[allow(clippy::identity_op)] // 装備ごとの内訳をそのまま残すため 0 項も明記する
     fn test_brd_pld_evasion_breakdown() {
         use crate::character_profile::JobLevel;
         use crate::skills::default_skills;
@@ -2280,4 +3055,135 @@ mod tests {
                    "evasion_bonus mismatch (Brd 2100JP gift PhysicalEvasion=22)");
         assert_eq!(result.evasion, 1237, "evasion total mismatch");
     }
+
+    #[test]
+    fn test_skill_caps_native() {
+        let war = skill_caps_native("War", 99).unwrap();
+        let blm = skill_caps_native("Blm", 99).unwrap();
+        assert_eq!(war.len(), SkillKind::VARIANTS.len());
+        assert_ne!(war, blm);
+        assert_eq!(war["GreatAxe"], 424);
+        assert_eq!(war["Elemental"], 0);
+        assert_eq!(blm["GreatAxe"], 0);
+        assert_eq!(blm["Elemental"], 424);
+
+        let err = skill_caps_native("Xxx", 99).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidJob);
+        let err = skill_caps_native("War", 100).unwrap_err();
+        assert_eq!(err.code, ErrorCode::OutOfRange);
+        assert_eq!(err.field.as_deref(), Some("lv"));
+    }
+
+    #[test]
+    fn test_validate_profile_native() {
+        let mut profile = CharacterProfile::new("Test".to_string(), Race::Hum);
+        profile.set_job_level(Job::War, 99, 50);
+        let result = validate_profile_native(serde_json::to_value(&profile).unwrap());
+        assert!(result.valid);
+        assert!(result.errors.is_empty());
+
+        profile.name = String::new();
+        profile.merit_points.str_ = 20;
+        let mut value = serde_json::to_value(&profile).unwrap();
+        value["job_levels"]["War"]["level"] = serde_json::json!(100);
+        let result = validate_profile_native(value);
+        assert!(!result.valid);
+        assert_eq!(
+            result.errors,
+            vec![
+                "name must not be empty",
+                "War level must be between 0 and 99: 100",
+                "Str merit point rank must be between 0 and 15: 20",
+            ]
+        );
+
+        // 形式不正もエラーメッセージとして返す
+        let result = validate_profile_native(serde_json::json!({ "name": "Test" }));
+        assert!(!result.valid);
+        assert_eq!(result.errors.len(), 1);
+        assert!(
+            result.errors[0].starts_with("Invalid profile:"),
+            "{:?}",
+            result.errors
+        );
+    }
+
+    #[test]
+    fn test_versions_are_not_empty() {
+        assert!(!get_version().is_empty());
+        assert_eq!(get_version(), env!("CARGO_PKG_VERSION"));
+        assert!(!get_data_version().is_empty());
+        assert_eq!(get_data_version(), DATA_VERSION);
+    }
+
+    #[test]
+    fn test_status_result_json_keys() {
+        let chara = war99_input().into_chara(BonusStats::default()).unwrap();
+        let value = serde_json::to_value(chara_to_status_result(&chara)).unwrap();
+        assert_eq!(value["str"], chara.status(StatusKind::Str));
+        assert!(value.get("int").is_some());
+        assert!(value.get("str_").is_none());
+    }
+
+    #[test]
+    fn test_merit_points_input_str_key() {
+        for key in ["str", "str_"] {
+            let input: MeritPointsInput =
+                serde_json::from_value(serde_json::json!({ key: 3, "int": 2 })).unwrap();
+            let merit = MeritPoints::from(input);
+            assert_eq!((merit.str_, merit.int), (3, 2), "{}", key);
+        }
+        let value = serde_json::to_value(MeritPointsInput::default()).unwrap();
+        assert!(value.get("str").is_some());
+    }
+
+    /// JP カテゴリに基本能力値を上げるものは無く、効果は派生値にだけ出る。
+    /// 効果のある全カテゴリ (`jp_category_effects`) について、対象の派生値が増えることを確認する
+    #[test]
+    fn test_job_point_category_derived_values() {
+        use crate::job_points::JobPointCategory::*;
+
+        let result = |job, category| {
+            let mut allocation = enum_map::EnumMap::default();
+            allocation[category] = 10;
+            let chara = Chara::builder()
+                .race(Race::Hum)
+                .main_job(job, 99)
+                .master_lv(0)
+                .job_point_allocation(allocation)
+                .bonus_stats(BonusStats {
+                    ranged_weapon_skill_id: Some(26), // 射撃
+                    ..BonusStats::default()
+                })
+                .build()
+                .unwrap();
+            chara_to_status_result(&chara)
+        };
+        let base_stats =
+            |r: &StatusResult| [r.hp, r.mp, r.str_, r.dex, r.vit, r.agi, r.int, r.mnd, r.chr];
+        type Derived = fn(&StatusResult) -> i32;
+        let cases: [(Job, _, Derived); 6] = [
+            // War: ダブルアタック効果 = 物理攻撃力 +1/rank
+            (Job::War, Category9, |r| r.main_attack),
+            // Cor: 遠隔命中アップ = 飛命 +1/rank
+            (Job::Cor, Category7, |r| r.ranged_accuracy.unwrap()),
+            (Job::Whm, Category3, |r| r.magic_accuracy_bonus),
+            (Job::Blm, Category5, |r| r.magic_accuracy_bonus),
+            (Job::Rdm, Category3, |r| r.magic_accuracy_bonus),
+            (Job::Rdm, Category5, |r| r.magic_attack),
+        ];
+        for (job, category, derived) in cases {
+            // 同じランクを効果のない Category0 に振った構成と比べ、累計 JP (ギフト) を揃える
+            let allocated = result(job, category);
+            let baseline = result(job, Category0);
+            assert_eq!(
+                derived(&allocated),
+                derived(&baseline) + 10,
+                "{:?} {:?}",
+                job,
+                category
+            );
+            assert_eq!(base_stats(&allocated), base_stats(&baseline));
+        }
+    }
 }
@@ -147,7 +147,7 @@ export const AUGMENT_JA_TO_EN = [
 export const BASE_STATS = [
     { key: 'Hp', resultKey: 'hp', equipKey: 'hp', pctKey: 'hp_pct' },
     { key: 'Mp', resultKey: 'mp', equipKey: 'mp', pctKey: 'mp_pct' },
-    { key: 'Str', resultKey: 'str_', equipKey: 'str' },
+    { key: 'Str', resultKey: 'str', equipKey: 'str' },
     { key: 'Dex', resultKey: 'dex', equipKey: 'dex' },
     { key: 'Vit', resultKey: 'vit', equipKey: 'vit' },
     { key: 'Agi', resultKey: 'agi', equipKey: 'agi' },
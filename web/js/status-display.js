@@ -145,7 +145,7 @@ export async function updateEquipEditStatus(deps) {
 
         const bonusStats = {
             hp: equip.hp, mp: equip.mp,
-            str_: equip.str, dex: equip.dex, vit: equip.vit,
+            str: equip.str, dex: equip.dex, vit: equip.vit,
             agi: equip.agi, int: equip.int, mnd: equip.mnd, chr: equip.chr,
             def: equip.def,
             magic_def_bonus: equip.magic_def_bonus,
@@ -177,7 +177,7 @@ export async function updateEquipEditStatus(deps) {
         // === 左パネル: 基本 9 ステ (素 / 装備 / 合計) ===
         document.getElementById('equipBaseHp').textContent = baseStats.hp || 0;
         document.getElementById('equipBaseMp').textContent = baseStats.mp || 0;
-        document.getElementById('equipBaseStr').textContent = baseStats.str_ || 0;
+        document.getElementById('equipBaseStr').textContent = baseStats.str || 0;
         document.getElementById('equipBaseDex').textContent = baseStats.dex || 0;
         document.getElementById('equipBaseVit').textContent = baseStats.vit || 0;
         document.getElementById('equipBaseAgi').textContent = baseStats.agi || 0;
@@ -197,7 +197,7 @@ export async function updateEquipEditStatus(deps) {
 
         document.getElementById('equipTotalHp').textContent = totalStats.hp || 0;
         document.getElementById('equipTotalMp').textContent = totalStats.mp || 0;
-        document.getElementById('equipTotalStr').textContent = totalStats.str_ || 0;
+        document.getElementById('equipTotalStr').textContent = totalStats.str || 0;
         document.getElementById('equipTotalDex').textContent = totalStats.dex || 0;
         document.getElementById('equipTotalVit').textContent = totalStats.vit || 0;
         document.getElementById('equipTotalAgi').textContent = totalStats.agi || 0;
@@ -291,7 +291,7 @@ export async function updateEquipEditStatus(deps) {
         setText('statRaSkill', formatWeaponSkill(totalStats.ranged_weapon_skill, totalStats.ranged_weapon_skill_value));
         setText('statRaAtk', totalStats.ranged_attack != null ? totalStats.ranged_attack : '-');
         setText('statRaAcc', totalStats.ranged_accuracy != null ? totalStats.ranged_accuracy : '-');
-        setText('statRaStr', totalStats.str_ || '-');
+        setText('statRaStr', totalStats.str || '-');
         setText('statRaAgi', totalStats.agi || '-');
         setText('statRaStp', numOrDash(totalStats.store_tp));
 
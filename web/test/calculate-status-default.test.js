@@ -0,0 +1,102 @@
+// WASM calculate_status の default_on_error (UI 初期状態向けフォールバック) の検証。
+//
+// 主な対象:
+//   1. default_on_error: true で不正入力 → Hum/War99 のデフォルト結果 + warning
+//   2. default_on_error 未指定 / false で不正入力 → 従来どおりエラー
+//   3. 正常な入力には warning が付かないこと
+//
+// 事前に web/pkg を生成しておく (rust/ で実行):
+//   wasm-pack build --target web --out-dir ../web/pkg
+// 実行: node web/test/calculate-status-default.test.js
+
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+const { pathToFileURL } = require('url');
+
+const pkgDir = path.join(__dirname, '..', 'pkg');
+
+// pkg は --target web の ES モジュールのため、.mjs に複製して読み込み、wasm は同期初期化する
+async function loadWasm() {
+    const jsPath = path.join(pkgDir, 'ff11sim.js');
+    if (!fs.existsSync(jsPath)) {
+        console.error('web/pkg がありません。rust/ で wasm-pack build --target web --out-dir ../web/pkg を実行してください');
+        process.exit(1);
+    }
+    const tmp = path.join(os.tmpdir(), `ff11sim-${process.pid}.mjs`);
+    fs.copyFileSync(jsPath, tmp);
+    try {
+        const wasm = await import(pathToFileURL(tmp).href);
+        wasm.initSync({ module: fs.readFileSync(path.join(pkgDir, 'ff11sim_bg.wasm')) });
+        return wasm;
+    } finally {
+        fs.unlinkSync(tmp);
+    }
+}
+
+let pass = 0;
+let fail = 0;
+function check(label, got, expected) {
+    if (got === expected) {
+        console.log(`  PASS  ${label}: ${got}`);
+        pass++;
+    } else {
+        console.log(`  FAIL  ${label}: got ${got}, expected ${expected}`);
+        fail++;
+    }
+}
+
+// 例外として返された ApiError ({ code, message, field }) を取り出す
+function errorOf(fn) {
+    try {
+        fn();
+    } catch (e) {
+        return e;
+    }
+    return null;
+}
+
+// calculate_status(race, main_job, main_lv, support_job, support_lv, master_lv, merit, bonus, default_on_error)
+function calc(wasm, race, mainJob, mainLv, defaultOnError) {
+    return wasm.calculate_status(race, mainJob, mainLv, undefined, undefined, 0, undefined, undefined, defaultOnError);
+}
+
+(async () => {
+    const wasm = await loadWasm();
+    const fallback = calc(wasm, 'Hum', 'War', 99, false);
+
+    console.log('=== default_on_error: true ===');
+    for (const [label, race, job, lv, cause] of [
+        ['main_job 空文字', 'Elv', '', 99, 'Invalid main job'],
+        ['main_job 不正', 'Elv', 'Xyz', 99, 'Invalid main job'],
+        ['race 不正', 'Xyz', 'Blm', 99, 'Invalid race'],
+        ['main_lv 範囲外', 'Elv', 'Blm', 100, 'main_lv'],
+    ]) {
+        const r = calc(wasm, race, job, lv, true);
+        check(`${label} → デフォルトの hp`, r.hp, fallback.hp);
+        check(`${label} → デフォルトの str`, r.str, fallback.str);
+        check(`${label} → warning に原因を含む`, typeof r.warning === 'string' && r.warning.includes(cause), true);
+    }
+
+    console.log('\n=== default_on_error: false / 未指定 ===');
+    {
+        const e = errorOf(() => calc(wasm, 'Hum', '', 99, false));
+        check('main_job 空文字 code', e && e.code, 'INVALID_JOB');
+        check('main_job 空文字 field', e && e.field, 'main_job');
+    }
+    {
+        const e = errorOf(() => calc(wasm, 'Hum', 'War', 100, undefined));
+        check('main_lv 範囲外 code', e && e.code, 'OUT_OF_RANGE');
+    }
+
+    console.log('\n=== 正常な入力 ===');
+    {
+        const r = calc(wasm, 'Hum', 'War', 99, true);
+        check('warning なし', r.warning, undefined);
+        check('hp は通常計算と同じ', r.hp, fallback.hp);
+    }
+
+    console.log('');
+    console.log(`${pass} passed, ${fail} failed`);
+    process.exit(fail > 0 ? 1 : 0);
+})();
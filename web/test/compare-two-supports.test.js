@@ -0,0 +1,110 @@
+// WASM compare_two_supports (サポートジョブ 2 案の A/B 比較) の検証。
+//
+// 主な対象:
+//   1. 2 案のステータスと差分 (diff = a - b) が返ること
+//   2. どちらかのサポートジョブが未レベルのときエラー (JOB_NOT_LEVELED) になること
+//
+// 事前に web/pkg を生成しておく (rust/ で実行):
+//   wasm-pack build --target web --out-dir ../web/pkg
+// 実行: node web/test/compare-two-supports.test.js
+
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+const { pathToFileURL } = require('url');
+
+const pkgDir = path.join(__dirname, '..', 'pkg');
+
+// pkg は --target web の ES モジュールのため、.mjs に複製して読み込み、wasm は同期初期化する
+async function loadWasm() {
+    const jsPath = path.join(pkgDir, 'ff11sim.js');
+    if (!fs.existsSync(jsPath)) {
+        console.error('web/pkg がありません。rust/ で wasm-pack build --target web --out-dir ../web/pkg を実行してください');
+        process.exit(1);
+    }
+    const tmp = path.join(os.tmpdir(), `ff11sim-${process.pid}.mjs`);
+    fs.copyFileSync(jsPath, tmp);
+    try {
+        const wasm = await import(pathToFileURL(tmp).href);
+        wasm.initSync({ module: fs.readFileSync(path.join(pkgDir, 'ff11sim_bg.wasm')) });
+        return wasm;
+    } finally {
+        fs.unlinkSync(tmp);
+    }
+}
+
+let pass = 0;
+let fail = 0;
+function check(label, got, expected) {
+    if (got === expected) {
+        console.log(`  PASS  ${label}: ${got}`);
+        pass++;
+    } else {
+        console.log(`  FAIL  ${label}: got ${got}, expected ${expected}`);
+        fail++;
+    }
+}
+
+// 指定ジョブのみレベルを持つプロファイル (それ以外は未レベル)
+function makeProfile(wasm, levels) {
+    const job_levels = {};
+    for (const job of wasm.get_jobs()) {
+        job_levels[job] = { level: levels[job] || 0, master_lv: 0 };
+    }
+    return { name: 'test', race: 'Hum', job_levels };
+}
+
+// 例外として返された ApiError ({ code, message, field }) を取り出す
+function errorOf(fn) {
+    try {
+        fn();
+    } catch (e) {
+        return e;
+    }
+    return null;
+}
+
+(async () => {
+    const wasm = await loadWasm();
+    const profile = makeProfile(wasm, { War: 99, Sam: 99, Nin: 99 });
+
+    console.log('=== War99 /Sam vs /Nin ===');
+    {
+        const r = wasm.compare_two_supports(profile, 'War', 'Sam', 'Nin');
+        check('a.hp > 0', r.a.hp > 0, true);
+        for (const key of ['hp', 'mp', 'str', 'dex', 'vit', 'agi', 'int', 'mnd', 'chr']) {
+            check(`diff.${key} = a - b`, r.diff[key], r.a[key] - r.b[key]);
+        }
+    }
+    {
+        // 同じサポート同士は差分 0
+        const r = wasm.compare_two_supports(profile, 'War', 'Sam', 'Sam');
+        check('同一サポートの diff.hp', r.diff.hp, 0);
+    }
+
+    console.log('\n=== 未レベルのサポートジョブはエラー ===');
+    {
+        const e = errorOf(() => wasm.compare_two_supports(profile, 'War', 'Sam', 'Drk'));
+        check('sub_b 未レベル code', e && e.code, 'JOB_NOT_LEVELED');
+        check('sub_b 未レベル field', e && e.field, 'support_job');
+    }
+    {
+        const e = errorOf(() => wasm.compare_two_supports(profile, 'War', 'Drk', 'Nin'));
+        check('sub_a 未レベル code', e && e.code, 'JOB_NOT_LEVELED');
+    }
+    {
+        // メインジョブ未レベルも同じコード
+        const e = errorOf(() => wasm.compare_two_supports(profile, 'Drk', 'Sam', 'Nin'));
+        check('main 未レベル code', e && e.code, 'JOB_NOT_LEVELED');
+        check('main 未レベル field', e && e.field, 'main_job');
+    }
+    {
+        const e = errorOf(() => wasm.compare_two_supports(profile, 'War', 'Xyz', 'Nin'));
+        check('不正なジョブ名 code', e && e.code, 'INVALID_JOB');
+        check('不正なジョブ名 field', e && e.field, 'sub_a');
+    }
+
+    console.log('');
+    console.log(`${pass} passed, ${fail} failed`);
+    process.exit(fail > 0 ? 1 : 0);
+})();